@@ -0,0 +1,35 @@
+
+use std::fmt;
+
+use borsh;
+use borsh::io;
+
+impl<K, D> borsh::BorshSerialize for ::Text<K, D>
+where
+    K: ::Kind,
+    D: ::Dynamic,
+{
+    fn serialize<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        self.as_str().serialize(writer)
+    }
+}
+
+impl<K, D> borsh::BorshDeserialize for ::Text<K, D>
+where
+    K: ::Kind,
+    D: ::Dynamic,
+    <<K as ::Kind>::Check as ::Check>::Error: fmt::Display,
+{
+    fn deserialize_reader<R>(reader: &mut R) -> io::Result<Self>
+    where
+        R: io::Read,
+    {
+        let value = String::deserialize_reader(reader)?;
+        ::Text::try_from_string(value).map_err(|error| {
+            io::Error::new(io::ErrorKind::InvalidData, error.without_value().explain())
+        })
+    }
+}
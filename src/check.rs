@@ -6,6 +6,7 @@
 //!
 //! See the `Kind` trait for an example on how to associate a check with a kind.
 
+use std::collections;
 use std::error;
 use std::fmt;
 
@@ -28,6 +29,11 @@ impl fmt::Display for NotEmptyError {
     }
 }
 
+impl ::CheckErrorCode for NotEmptyError {
+
+    fn code(&self) -> &'static str { "not_empty" }
+}
+
 
 /// Ensure a value is not empty.
 ///
@@ -64,6 +70,11 @@ impl ::Check for NotEmpty {
     }
 }
 
+impl ::BoundedBytes for NotEmpty {
+
+    const MAX: Option<usize> = None;
+}
+
 /// Signals that a value is invalid because it contained a newline.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SingleLineError;
@@ -80,6 +91,11 @@ impl fmt::Display for SingleLineError {
     }
 }
 
+impl ::CheckErrorCode for SingleLineError {
+
+    fn code(&self) -> &'static str { "single_line" }
+}
+
 /// Ensure a value does not contain newlines and is therefor on a single line.
 ///
 /// A trailing newline will also cause the check to fail.
@@ -121,6 +137,11 @@ impl ::Check for SingleLine {
     }
 }
 
+impl ::BoundedBytes for SingleLine {
+
+    const MAX: Option<usize> = None;
+}
+
 /// Signals that a value is invalid because it contained whitespaces.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NoWhitespaceError {
@@ -144,6 +165,11 @@ impl fmt::Display for NoWhitespaceError {
     }
 }
 
+impl ::CheckErrorCode for NoWhitespaceError {
+
+    fn code(&self) -> &'static str { "no_whitespace" }
+}
+
 /// Ensure a value does not contain whitespaces.
 ///
 /// # Examples
@@ -185,6 +211,11 @@ impl ::Check for NoWhitespace {
     }
 }
 
+impl ::BoundedBytes for NoWhitespace {
+
+    const MAX: Option<usize> = None;
+}
+
 /// Signals that a value is invalid because it contained control characters.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NoControlError {
@@ -208,6 +239,11 @@ impl fmt::Display for NoControlError {
     }
 }
 
+impl ::CheckErrorCode for NoControlError {
+
+    fn code(&self) -> &'static str { "no_control" }
+}
+
 /// Ensure a value does not contain control characters.
 ///
 /// # Examples
@@ -249,6 +285,11 @@ impl ::Check for NoControl {
     }
 }
 
+impl ::BoundedBytes for NoControl {
+
+    const MAX: Option<usize> = None;
+}
+
 /// Signals that a value is invalid because it failed a check when trimmed.
 ///
 /// The contained value is the error of the failed check.
@@ -271,6 +312,13 @@ where
     }
 }
 
+impl<E> ::CheckErrorCode for WhenTrimmedError<E>
+where
+    E: ::CheckErrorCode,
+{
+    fn code(&self) -> &'static str { self.0.code() }
+}
+
 /// Ensure a value passes a check when whitespace is trimmed off the beginning and end.
 ///
 /// # Examples
@@ -313,6 +361,13 @@ where
     }
 }
 
+impl<T> ::BoundedBytes for WhenTrimmed<T>
+where
+    T: ::Check,
+{
+    const MAX: Option<usize> = None;
+}
+
 /// Signals that a value is invalid because it failed one of two checks.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AndError<E1, E2> {
@@ -343,9 +398,23 @@ where
     }
 }
 
+impl<E1, E2> ::CheckErrorCode for AndError<E1, E2>
+where
+    E1: ::CheckErrorCode,
+    E2: ::CheckErrorCode,
+{
+    fn code(&self) -> &'static str {
+        match *self {
+            AndError::Err1(ref error) => error.code(),
+            AndError::Err2(ref error) => error.code(),
+        }
+    }
+}
+
 /// Ensure a value passes two checks.
 ///
-/// This type can be nested to combine any number of checks.
+/// This type can be nested to combine any number of checks. The `BoundedBytes` limit of the
+/// combination is the lower of both inner limits.
 ///
 /// # Examples
 ///
@@ -389,6 +458,184 @@ where
     }
 }
 
+impl<T1, T2> ::BoundedBytes for And<T1, T2>
+where
+    T1: ::BoundedBytes,
+    T2: ::BoundedBytes,
+{
+    const MAX: Option<usize> = match (T1::MAX, T2::MAX) {
+        (Some(max_1), Some(max_2)) => Some(if max_1 < max_2 { max_1 } else { max_2 }),
+        (Some(max), None) | (None, Some(max)) => Some(max),
+        (None, None) => None,
+    };
+}
+
+/// Combine any number of checks into nested `And` checks.
+///
+/// The macro is exported at the crate root and also available as `check::all!`.
+/// `all!(A, B, C)` expands to `And<A, And<B, C>>`. The error of the combination nests the same
+/// way, so the third check failing produces `AndError::Err2(AndError::Err2(error))`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+/// use textkind::check::{AndError, NotEmpty, NoControl, MaxBytes256};
+///
+/// type Label = all!(NotEmpty, NoControl, MaxBytes256);
+///
+/// assert!(Label::check("foo").is_ok());
+/// assert!(Label::check("").is_err());
+///
+/// match Label::check("\t") {
+///     Err(AndError::Err2(AndError::Err1(_))) => (),
+///     _ => panic!("expected a control character error"),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! all {
+    ($check:ty $(,)*) => { $check };
+    ($check:ty, $($rest:ty),+ $(,)*) => {
+        $crate::check::And<$check, $crate::all!($($rest),+)>
+    };
+}
+
+pub use all;
+
+/// Signals that a value is invalid because it failed both of two alternative checks.
+///
+/// It is a tuple containing the errors of the left and the right check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrError<E1, E2>(pub E1, pub E2);
+
+impl<E1, E2> error::Error for OrError<E1, E2>
+where
+    E1: error::Error,
+    E2: error::Error,
+{
+    fn description(&self) -> &str { "combined Or error" }
+}
+
+impl<E1, E2> fmt::Display for OrError<E1, E2>
+where
+    E1: fmt::Display,
+    E2: fmt::Display,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "value matches none of the alternatives ({}; {})", self.0, self.1)
+    }
+}
+
+impl<E1, E2> ::CheckErrorCode for OrError<E1, E2> {
+
+    fn code(&self) -> &'static str { "or" }
+}
+
+/// Ensure a value passes at least one of two checks.
+///
+/// The right check is only run when the left check fails. This type can be nested to
+/// combine any number of alternatives. The `BoundedBytes` limit of the combination is the
+/// higher of both inner limits, or no limit if either alternative is unbounded.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// type DigitsOrLetters = textkind::check::Or<
+///     textkind::check::AsciiDigit,
+///     textkind::check::AsciiAlpha,
+/// >;
+///
+/// assert!(DigitsOrLetters::check("123").is_ok());
+/// assert!(DigitsOrLetters::check("abc").is_ok());
+///
+/// assert!(DigitsOrLetters::check("abc123").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Or<T1, T2> {
+    _check_1: T1,
+    _check_2: T2,
+    _unconstructable: ::Void,
+}
+
+impl<T1, T2> ::Check for Or<T1, T2>
+where
+    T1: ::Check,
+    T2: ::Check,
+{
+    type Error = OrError<T1::Error, T2::Error>;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        match T1::check(value) {
+            Ok(()) => Ok(()),
+            Err(error_1) => T2::check(value).map_err(|error_2| OrError(error_1, error_2)),
+        }
+    }
+}
+
+impl<T1, T2> ::BoundedBytes for Or<T1, T2>
+where
+    T1: ::BoundedBytes,
+    T2: ::BoundedBytes,
+{
+    const MAX: Option<usize> = match (T1::MAX, T2::MAX) {
+        (Some(max_1), Some(max_2)) => Some(if max_1 > max_2 { max_1 } else { max_2 }),
+        _ => None,
+    };
+}
+
+/// Combine any number of checks into nested `Or` checks.
+///
+/// The macro is exported at the crate root and also available as `check::any!`.
+/// `any!(A, B, C)` expands to `Or<A, Or<B, C>>` and passes when any of the checks passes.
+/// When none matches, the error contains the errors of all alternatives in the same nesting.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+/// use textkind::check::{AsciiAlpha, AsciiDigit, Semver};
+///
+/// type Tag = any!(AsciiDigit, AsciiAlpha, Semver);
+///
+/// assert!(Tag::check("123").is_ok());
+/// assert!(Tag::check("1.2.3").is_ok());
+/// assert!(Tag::check("1.2.3!").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! any {
+    ($check:ty $(,)*) => { $check };
+    ($check:ty, $($rest:ty),+ $(,)*) => {
+        $crate::check::Or<$check, $crate::any!($($rest),+)>
+    };
+}
+
+pub use any;
+
 /// Signals that a value is invalid because it begins with whitespace.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TrimmedLeftError;
@@ -405,6 +652,11 @@ impl fmt::Display for TrimmedLeftError {
     }
 }
 
+impl ::CheckErrorCode for TrimmedLeftError {
+
+    fn code(&self) -> &'static str { "trimmed_left" }
+}
+
 /// Ensure a value doesn't start with whitespace.
 ///
 /// # Examples
@@ -441,6 +693,11 @@ impl ::Check for TrimmedLeft {
     }
 }
 
+impl ::BoundedBytes for TrimmedLeft {
+
+    const MAX: Option<usize> = None;
+}
+
 /// Signals that a value is invalid because it ends with whitespace.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TrimmedRightError;
@@ -457,6 +714,11 @@ impl fmt::Display for TrimmedRightError {
     }
 }
 
+impl ::CheckErrorCode for TrimmedRightError {
+
+    fn code(&self) -> &'static str { "trimmed_right" }
+}
+
 /// Ensure a value doesn't end with whitespace.
 ///
 /// # Examples
@@ -493,6 +755,11 @@ impl ::Check for TrimmedRight {
     }
 }
 
+impl ::BoundedBytes for TrimmedRight {
+
+    const MAX: Option<usize> = None;
+}
+
 /// Signals that a value is invalid because it only contains whitespace.
 ///
 /// This is used for improved error messages when a value must be trimmed but only contains
@@ -512,6 +779,11 @@ impl fmt::Display for TrimmedOnlyError {
     }
 }
 
+impl ::CheckErrorCode for TrimmedOnlyError {
+
+    fn code(&self) -> &'static str { "trimmed_only" }
+}
+
 /// Signals that a value is invalid because it starts and ends with whitespace.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TrimmedBothError;
@@ -528,6 +800,11 @@ impl fmt::Display for TrimmedBothError {
     }
 }
 
+impl ::CheckErrorCode for TrimmedBothError {
+
+    fn code(&self) -> &'static str { "trimmed_both" }
+}
+
 /// Signals that a value is invalid because it starts or ends with whitespace.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TrimmedError {
@@ -558,6 +835,18 @@ impl fmt::Display for TrimmedError {
     }
 }
 
+impl ::CheckErrorCode for TrimmedError {
+
+    fn code(&self) -> &'static str {
+        match *self {
+            TrimmedError::Left(ref error) => error.code(),
+            TrimmedError::Right(ref error) => error.code(),
+            TrimmedError::Both(ref error) => error.code(),
+            TrimmedError::Only(ref error) => error.code(),
+        }
+    }
+}
+
 /// Ensure a value doesn't begin or end with whitespace.
 ///
 /// # Examples
@@ -603,6 +892,11 @@ impl ::Check for Trimmed {
     }
 }
 
+impl ::BoundedBytes for Trimmed {
+
+    const MAX: Option<usize> = None;
+}
+
 /// Signals that a value is not a valid lax identifier.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IdentifierLaxError {
@@ -629,6 +923,16 @@ impl fmt::Display for IdentifierLaxError {
     }
 }
 
+impl ::CheckErrorCode for IdentifierLaxError {
+
+    fn code(&self) -> &'static str {
+        match *self {
+            IdentifierLaxError::Empty(ref error) => error.code(),
+            IdentifierLaxError::InvalidChar(_) => "identifier_lax_char",
+        }
+    }
+}
+
 /// Ensure a value is a valid relaxed identifier.
 ///
 /// To be a valid relaxed identifier, a value has to be not empty and only contain the
@@ -682,6 +986,11 @@ impl ::Check for IdentifierLax {
     }
 }
 
+impl ::BoundedBytes for IdentifierLax {
+
+    const MAX: Option<usize> = None;
+}
+
 /// Signals that a value is not a valid lax identifier.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IdentifierError {
@@ -712,6 +1021,17 @@ impl fmt::Display for IdentifierError {
     }
 }
 
+impl ::CheckErrorCode for IdentifierError {
+
+    fn code(&self) -> &'static str {
+        match *self {
+            IdentifierError::Empty(ref error) => error.code(),
+            IdentifierError::InvalidStartChar(_) => "identifier_start_char",
+            IdentifierError::InvalidRestChar(_) => "identifier_char",
+        }
+    }
+}
+
 /// Ensure a value is a valid identifier.
 ///
 /// To be a valid identifier, a value has to be not empty and only contain the following
@@ -772,6 +1092,11 @@ impl ::Check for Identifier {
     }
 }
 
+impl ::BoundedBytes for Identifier {
+
+    const MAX: Option<usize> = None;
+}
+
 /// Signals that a value is too large bytewise to be valid.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MaxBytesError {
@@ -793,54 +1118,3201 @@ impl fmt::Display for MaxBytesError {
     }
 }
 
-macro_rules! gen_max_bytes {
-    ($name:ident: $max:expr) => {
-
-        /// Ensure a value has a byte count lower than the specified number.
-        ///
-        /// # Examples
-        ///
-        /// Basic usage for `MaxBytes256`. The other `MaxBytes*` checks work the same but
-        /// check for different byte lengths.
-        ///
-        /// ```
-        /// extern crate textkind;
-        /// # fn main() { example().expect("no errors") }
-        /// # fn example() -> Result<(), Box<::std::error::Error>> {
-        /// use textkind::Check;
-        ///
-        /// let valid = "X".repeat(256);
-        /// let invalid = "X".repeat(257);
-        ///
-        /// assert!(textkind::check::MaxBytes256::check(&valid).is_ok());
-        /// assert!(textkind::check::MaxBytes256::check(&invalid).is_err());
-        /// # Ok(())
-        /// # }
-        /// ```
-        #[allow(missing_debug_implementations)]
-        pub struct $name {
-            _unconstructable: ::Void,
-        }
+impl ::CheckErrorCode for MaxBytesError {
 
-        impl ::Check for $name {
+    fn code(&self) -> &'static str { "max_bytes" }
+}
 
-            type Error = MaxBytesError;
+/// Ensure a value has a byte count not exceeding `N`.
+///
+/// The `MaxBytes64`, `MaxBytes256`, `MaxBytes512` and `MaxBytes1024` aliases are provided for
+/// common limits.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// let valid = "X".repeat(200);
+/// let invalid = "X".repeat(201);
+///
+/// assert!(textkind::check::MaxBytes::<200>::check(&valid).is_ok());
+/// assert!(textkind::check::MaxBytes::<200>::check(&invalid).is_err());
+///
+/// assert!(textkind::check::MaxBytes256::check(&invalid).is_ok());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MaxBytes<const N: usize> {
+    _unconstructable: ::Void,
+}
 
-            fn check(value: &str) -> Result<(), Self::Error> {
-                if value.as_bytes().len() <= $max {
-                    Ok(())
-                } else {
-                    Err(MaxBytesError {
-                        max: $max,
-                        len: value.as_bytes().len(),
-                    })
-                }
-            }
+impl<const N: usize> ::Check for MaxBytes<N> {
+
+    type Error = MaxBytesError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        if value.len() <= N {
+            Ok(())
+        } else {
+            Err(MaxBytesError {
+                max: N,
+                len: value.len(),
+            })
         }
     }
 }
 
-gen_max_bytes!(MaxBytes256: 256);
-gen_max_bytes!(MaxBytes512: 512);
-gen_max_bytes!(MaxBytes1024: 1024);
+impl<const N: usize> ::BoundedBytes for MaxBytes<N> {
+
+    const MAX: Option<usize> = Some(N);
+}
+
+/// Ensure a value has at most 64 bytes.
+pub type MaxBytes64 = MaxBytes<64>;
+
+/// Ensure a value has at most 256 bytes.
+pub type MaxBytes256 = MaxBytes<256>;
+
+/// Ensure a value has at most 512 bytes.
+pub type MaxBytes512 = MaxBytes<512>;
+
+/// Ensure a value has at most 1024 bytes.
+pub type MaxBytes1024 = MaxBytes<1024>;
+
+/// Signals that a value is too small bytewise to be valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinBytesError {
+    /// Minimum required byte length.
+    pub min: usize,
+    /// Actual byte length of the value.
+    pub len: usize,
+}
+
+impl error::Error for MinBytesError {
+
+    fn description(&self) -> &str { "MinBytes error" }
+}
+
+impl fmt::Display for MinBytesError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "length of {} is below minimum of {}", self.len, self.min)
+    }
+}
+
+impl ::CheckErrorCode for MinBytesError {
+
+    fn code(&self) -> &'static str { "min_bytes" }
+}
+
+/// Ensure a value has a byte count of at least `N`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::MinBytes::<3>::check("foo").is_ok());
+/// assert!(textkind::check::MinBytes::<3>::check("fo").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MinBytes<const N: usize> {
+    _unconstructable: ::Void,
+}
+
+impl<const N: usize> ::Check for MinBytes<N> {
+
+    type Error = MinBytesError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        if value.len() >= N {
+            Ok(())
+        } else {
+            Err(MinBytesError {
+                min: N,
+                len: value.len(),
+            })
+        }
+    }
+}
+
+impl<const N: usize> ::BoundedBytes for MinBytes<N> {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Signals that a value's byte count is outside of an allowed range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRangeError {
+    /// Minimum required byte length.
+    pub min: usize,
+    /// Maximum allowed byte length.
+    pub max: usize,
+    /// Actual byte length of the value.
+    pub len: usize,
+}
+
+impl From<MinBytesError> for ByteRangeError {
+
+    fn from(error: MinBytesError) -> Self {
+        ByteRangeError { min: error.min, max: usize::MAX, len: error.len }
+    }
+}
+
+impl From<MaxBytesError> for ByteRangeError {
+
+    fn from(error: MaxBytesError) -> Self {
+        ByteRangeError { min: 0, max: error.max, len: error.len }
+    }
+}
+
+impl error::Error for ByteRangeError {
+
+    fn description(&self) -> &str { "ByteRange error" }
+}
+
+impl fmt::Display for ByteRangeError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "length of {} is outside of the range {} to {}",
+            self.len,
+            self.min,
+            self.max,
+        )
+    }
+}
+
+impl ::CheckErrorCode for ByteRangeError {
+
+    fn code(&self) -> &'static str { "byte_range" }
+}
+
+/// Ensure a value has a byte count between `MIN` and `MAX`, both inclusive.
+///
+/// This combines `MinBytes<MIN>` and `MaxBytes<MAX>` with a single error type. Using the check
+/// with `MIN` greater than `MAX` fails to compile.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::ByteRange::<4, 8>::check("foobar").is_ok());
+///
+/// assert!(textkind::check::ByteRange::<4, 8>::check("foo").is_err());
+/// assert!(textkind::check::ByteRange::<4, 8>::check("foobarbaz").is_err());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// A range with `MIN` greater than `MAX` is rejected when the check is used:
+///
+/// ```compile_fail
+/// extern crate textkind;
+/// # fn main() {
+/// use textkind::Check;
+///
+/// let _ = textkind::check::ByteRange::<8, 4>::check("foo");
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ByteRange<const MIN: usize, const MAX: usize> {
+    _unconstructable: ::Void,
+}
+
+impl<const MIN: usize, const MAX: usize> ByteRange<MIN, MAX> {
+
+    const _ASSERT: () = assert!(MIN <= MAX, "ByteRange requires MIN <= MAX");
+}
+
+impl<const MIN: usize, const MAX: usize> ::Check for ByteRange<MIN, MAX> {
+
+    type Error = ByteRangeError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        let () = Self::_ASSERT;
+        let error = |len| ByteRangeError { min: MIN, max: MAX, len };
+        <MinBytes<MIN> as ::Check>::check(value).map_err(|e| error(e.len))?;
+        <MaxBytes<MAX> as ::Check>::check(value).map_err(|e| error(e.len))?;
+        Ok(())
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> ::BoundedBytes for ByteRange<MIN, MAX> {
+
+    const MAX: Option<usize> = Some(MAX);
+}
+
+
+macro_rules! gen_ascii_class {
+    (
+        $(#[$check_meta:meta])*
+        check $name:ident;
+        $(#[$error_meta:meta])*
+        error $error:ident;
+        class $method:ident, $description:expr;
+        code $code:expr;
+    ) => {
+
+        $(#[$error_meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $error {
+            /// The first character that was not in the allowed class.
+            pub found: char,
+            /// The byte index of the character in the value.
+            pub index: usize,
+        }
+
+        impl error::Error for $error {
+
+            fn description(&self) -> &str { concat!(stringify!($name), " error") }
+        }
+
+        impl fmt::Display for $error {
+
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    fmt,
+                    "value contains character `{}` at index {} that is not {}",
+                    self.found.escape_default(),
+                    self.index,
+                    $description,
+                )
+            }
+        }
+
+        impl ::CheckErrorCode for $error {
+
+            fn code(&self) -> &'static str { $code }
+        }
+
+        $(#[$check_meta])*
+        #[allow(missing_debug_implementations)]
+        pub struct $name {
+            _unconstructable: ::Void,
+        }
+
+        impl ::Check for $name {
+
+            type Error = $error;
+
+            fn check(value: &str) -> Result<(), Self::Error> {
+                match value.char_indices().find(|&(_, c)| !c.$method()) {
+                    None => Ok(()),
+                    Some((index, found)) => Err($error { found, index }),
+                }
+            }
+        }
+
+        impl ::BoundedBytes for $name {
+
+            const MAX: Option<usize> = None;
+        }
+    }
+}
+
+gen_ascii_class! {
+    /// Ensure a value only contains ASCII alphabetic characters.
+    ///
+    /// The empty string is considered valid. Combine with `NotEmpty` to require a value.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// use textkind::Check;
+    ///
+    /// assert!(textkind::check::AsciiAlpha::check("foo").is_ok());
+    /// assert!(textkind::check::AsciiAlpha::check("").is_ok());
+    ///
+    /// assert!(textkind::check::AsciiAlpha::check("foo2").is_err());
+    /// assert!(textkind::check::AsciiAlpha::check("föo").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    check AsciiAlpha;
+    /// Signals that a value is invalid because it contained a non-alphabetic character.
+    error AsciiAlphaError;
+    class is_ascii_alphabetic, "ASCII alphabetic";
+    code "ascii_alpha";
+}
+
+gen_ascii_class! {
+    /// Ensure a value only contains ASCII digits.
+    ///
+    /// The empty string is considered valid. Combine with `NotEmpty` to require a value.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// use textkind::Check;
+    ///
+    /// assert!(textkind::check::AsciiDigit::check("0123").is_ok());
+    /// assert!(textkind::check::AsciiDigit::check("").is_ok());
+    ///
+    /// assert!(textkind::check::AsciiDigit::check("12a").is_err());
+    /// assert!(textkind::check::AsciiDigit::check("-1").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    check AsciiDigit;
+    /// Signals that a value is invalid because it contained a non-digit character.
+    error AsciiDigitError;
+    class is_ascii_digit, "an ASCII digit";
+    code "ascii_digit";
+}
+
+gen_ascii_class! {
+    /// Ensure a value only contains ASCII alphabetic characters and digits.
+    ///
+    /// The empty string is considered valid. Combine with `NotEmpty` to require a value.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// use textkind::Check;
+    ///
+    /// assert!(textkind::check::AsciiAlphanumeric::check("foo23").is_ok());
+    /// assert!(textkind::check::AsciiAlphanumeric::check("").is_ok());
+    ///
+    /// assert!(textkind::check::AsciiAlphanumeric::check("foo_23").is_err());
+    /// assert!(textkind::check::AsciiAlphanumeric::check("foo 23").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    check AsciiAlphanumeric;
+    /// Signals that a value is invalid because it contained a non-alphanumeric character.
+    error AsciiAlphanumericError;
+    class is_ascii_alphanumeric, "ASCII alphanumeric";
+    code "ascii_alphanumeric";
+}
+
+gen_ascii_class! {
+    /// Ensure a value only contains ASCII characters.
+    ///
+    /// The empty string is considered valid. Combine with `NotEmpty` to require a value.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// use textkind::Check;
+    ///
+    /// assert!(textkind::check::Ascii::check("foo bar!").is_ok());
+    /// assert!(textkind::check::Ascii::check("").is_ok());
+    ///
+    /// assert!(textkind::check::Ascii::check("käse").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    check Ascii;
+    /// Signals that a value is invalid because it contained a non-ASCII character.
+    error AsciiError;
+    class is_ascii, "ASCII";
+    code "ascii";
+}
+
+/// Identifies a version component for `SemverError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SemverComponent {
+    /// The major version component.
+    Major,
+    /// The minor version component.
+    Minor,
+    /// The patch version component.
+    Patch,
+}
+
+impl fmt::Display for SemverComponent {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SemverComponent::Major => write!(fmt, "major"),
+            SemverComponent::Minor => write!(fmt, "minor"),
+            SemverComponent::Patch => write!(fmt, "patch"),
+        }
+    }
+}
+
+/// Signals that a value is not a valid semantic version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SemverError {
+    /// A version component is missing or empty.
+    MissingComponent(SemverComponent),
+    /// A version component contains characters other than ASCII digits.
+    NonNumericComponent(SemverComponent),
+    /// A version component has a leading zero.
+    LeadingZero(SemverComponent),
+    /// The value has more than three version components.
+    ExtraComponent,
+    /// The pre-release part contains an empty or invalid identifier.
+    InvalidPreRelease,
+    /// The build metadata part contains an empty or invalid identifier.
+    InvalidBuild,
+}
+
+impl error::Error for SemverError {
+
+    fn description(&self) -> &str { "Semver error" }
+}
+
+impl fmt::Display for SemverError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SemverError::MissingComponent(component) =>
+                write!(fmt, "value is missing the {} version", component),
+            SemverError::NonNumericComponent(component) =>
+                write!(fmt, "value has a non-numeric {} version", component),
+            SemverError::LeadingZero(component) =>
+                write!(fmt, "value has a leading zero in the {} version", component),
+            SemverError::ExtraComponent =>
+                write!(fmt, "value has more than three version components"),
+            SemverError::InvalidPreRelease =>
+                write!(fmt, "value has an invalid pre-release identifier"),
+            SemverError::InvalidBuild =>
+                write!(fmt, "value has an invalid build metadata identifier"),
+        }
+    }
+}
+
+impl ::CheckErrorCode for SemverError {
+
+    fn code(&self) -> &'static str {
+        match *self {
+            SemverError::MissingComponent(_) => "semver_missing_component",
+            SemverError::NonNumericComponent(_) => "semver_non_numeric_component",
+            SemverError::LeadingZero(_) => "semver_leading_zero",
+            SemverError::ExtraComponent => "semver_extra_component",
+            SemverError::InvalidPreRelease => "semver_invalid_pre_release",
+            SemverError::InvalidBuild => "semver_invalid_build",
+        }
+    }
+}
+
+fn check_semver_core(value: &str) -> Result<(), SemverError> {
+    let mut parts = value.split('.');
+    let components = [SemverComponent::Major, SemverComponent::Minor, SemverComponent::Patch];
+    for &component in &components {
+        let part = match parts.next() {
+            Some(part) if !part.is_empty() => part,
+            _ => return Err(SemverError::MissingComponent(component)),
+        };
+        if !part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(SemverError::NonNumericComponent(component));
+        }
+        if part.len() > 1 && part.starts_with('0') {
+            return Err(SemverError::LeadingZero(component));
+        }
+    }
+    if parts.next().is_some() {
+        return Err(SemverError::ExtraComponent);
+    }
+    Ok(())
+}
+
+fn is_semver_identifiers(value: &str, allow_leading_zero: bool) -> bool {
+    value.split('.').all(|identifier| {
+        let valid_chars = identifier.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-');
+        let numeric = identifier.bytes().all(|b| b.is_ascii_digit());
+        let leading_zero = numeric && identifier.len() > 1 && identifier.starts_with('0');
+        !identifier.is_empty() && valid_chars && (allow_leading_zero || !leading_zero)
+    })
+}
+
+/// Ensure a value is a semantic version core.
+///
+/// This accepts `MAJOR.MINOR.PATCH` versions where every component is a non-empty sequence
+/// of ASCII digits without leading zeros. See `Semver` for a check that also accepts
+/// pre-release and build metadata parts.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::SemverCore::check("1.2.3").is_ok());
+/// assert!(textkind::check::SemverCore::check("0.10.0").is_ok());
+///
+/// assert!(textkind::check::SemverCore::check("1.2").is_err());
+/// assert!(textkind::check::SemverCore::check("1.02.3").is_err());
+/// assert!(textkind::check::SemverCore::check("1.2.3-rc.1").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SemverCore {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for SemverCore {
+
+    type Error = SemverError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        check_semver_core(value)
+    }
+}
+
+impl ::BoundedBytes for SemverCore {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Ensure a value is a semantic version.
+///
+/// This extends `SemverCore` with an optional `-` prefixed pre-release part and an optional
+/// `+` prefixed build metadata part. Both consist of non-empty dot-separated identifiers
+/// made of ASCII alphanumeric characters and hyphens. Numeric pre-release identifiers must
+/// not have leading zeros.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::Semver::check("1.2.3").is_ok());
+/// assert!(textkind::check::Semver::check("1.2.3-rc.1").is_ok());
+/// assert!(textkind::check::Semver::check("1.2.3-rc.1+build.05").is_ok());
+///
+/// assert!(textkind::check::Semver::check("1.2.3-").is_err());
+/// assert!(textkind::check::Semver::check("1.2.3-rc.01").is_err());
+/// assert!(textkind::check::Semver::check("1.2.3+build_5").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Semver {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for Semver {
+
+    type Error = SemverError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        let (value, build) = match value.find('+') {
+            Some(index) => (&value[..index], Some(&value[(index + 1)..])),
+            None => (value, None),
+        };
+        let (core, pre_release) = match value.find('-') {
+            Some(index) => (&value[..index], Some(&value[(index + 1)..])),
+            None => (value, None),
+        };
+        check_semver_core(core)?;
+        if let Some(pre_release) = pre_release {
+            if !is_semver_identifiers(pre_release, false) {
+                return Err(SemverError::InvalidPreRelease);
+            }
+        }
+        if let Some(build) = build {
+            if !is_semver_identifiers(build, true) {
+                return Err(SemverError::InvalidBuild);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ::BoundedBytes for Semver {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Words that `TitleCase` accepts in lowercase unless they start the value.
+pub const TITLE_CASE_STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "on", "or", "the",
+    "to", "with",
+];
+
+/// Signals that a value is invalid because a word does not start uppercase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TitleCaseError {
+    /// The first offending word.
+    pub word: String,
+    /// The byte index of the offending word in the value.
+    pub index: usize,
+}
+
+impl error::Error for TitleCaseError {
+
+    fn description(&self) -> &str { "TitleCase error" }
+}
+
+impl fmt::Display for TitleCaseError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "value contains word `{}` at index {} that does not start uppercase",
+            self.word,
+            self.index,
+        )
+    }
+}
+
+impl ::CheckErrorCode for TitleCaseError {
+
+    fn code(&self) -> &'static str { "title_case" }
+}
+
+/// Check a value for title case with the given stop words.
+///
+/// This is used by `TitleCase` and the checks generated by `title_case_check!`.
+#[doc(hidden)]
+pub fn check_title_case(value: &str, stop_words: &[&str]) -> Result<(), TitleCaseError> {
+    let words = value
+        .split(|c: char| c.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .enumerate();
+    for (position, word) in words {
+        let first = match word.chars().find(|c| c.is_alphabetic()) {
+            Some(first) => first,
+            None => continue,
+        };
+        if !first.is_lowercase() {
+            continue;
+        }
+        let bare = word.trim_matches(|c: char| !c.is_alphanumeric());
+        if position > 0 && stop_words.contains(&bare) {
+            continue;
+        }
+        return Err(TitleCaseError {
+            word: word.to_string(),
+            index: word.as_ptr() as usize - value.as_ptr() as usize,
+        });
+    }
+    Ok(())
+}
+
+/// Ensure every significant word of a value starts uppercase.
+///
+/// Words are separated by whitespace. The first alphabetic character of each word must not
+/// be lowercase, so words without alphabetic characters or with characters that have no case
+/// are accepted. Words in `TITLE_CASE_STOP_WORDS` may be lowercase unless they are the first
+/// word. Surrounding punctuation is ignored when matching stop words.
+///
+/// Use `title_case_check!` to define a check with a different stop word list.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::TitleCase::check("The Lord of the Rings").is_ok());
+/// assert!(textkind::check::TitleCase::check("Chapter 2: A New Hope").is_ok());
+///
+/// assert!(textkind::check::TitleCase::check("the Lord of the Rings").is_err());
+/// assert!(textkind::check::TitleCase::check("The lord of the Rings").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TitleCase {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for TitleCase {
+
+    type Error = TitleCaseError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        check_title_case(value, TITLE_CASE_STOP_WORDS)
+    }
+}
+
+impl ::BoundedBytes for TitleCase {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Define a title case check with a custom stop word list.
+///
+/// The generated check behaves like `TitleCase` but accepts the given words in lowercase
+/// instead of the ones in `TITLE_CASE_STOP_WORDS`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// title_case_check! {
+///     /// Title case with German stop words.
+///     GermanTitleCase: ["der", "die", "das", "und"]
+/// }
+///
+/// assert!(GermanTitleCase::check("Der Herr der Ringe").is_ok());
+/// assert!(GermanTitleCase::check("Der Herr of Ringe").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! title_case_check {
+    ($(#[$meta:meta])* $name:ident: [$($stop_word:expr),* $(,)*]) => {
+        $(#[$meta])*
+        #[allow(missing_debug_implementations)]
+        pub enum $name {}
+
+        impl $crate::Check for $name {
+
+            type Error = $crate::check::TitleCaseError;
+
+            fn check(value: &str) -> Result<(), Self::Error> {
+                $crate::check::check_title_case(value, &[$($stop_word),*])
+            }
+        }
+
+        impl $crate::BoundedBytes for $name {
+
+            const MAX: Option<usize> = None;
+        }
+    }
+}
+
+pub use title_case_check;
+
+/// Signals that a value is invalid because it does not contain a required substring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContainsError {
+    /// The substring that was not found.
+    pub needle: &'static str,
+}
+
+impl error::Error for ContainsError {
+
+    fn description(&self) -> &str { "Contains error" }
+}
+
+impl fmt::Display for ContainsError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "value does not contain `{}`", self.needle)
+    }
+}
+
+impl ::CheckErrorCode for ContainsError {
+
+    fn code(&self) -> &'static str { "contains" }
+}
+
+/// Define a check requiring a value to contain a substring.
+///
+/// The generated check fails with a `ContainsError` when the value does not contain the
+/// given `&'static str` needle.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// contains_check!(
+///     /// Ensure a value contains an `@`.
+///     HasAt, "@"
+/// );
+///
+/// assert!(HasAt::check("user@example.com").is_ok());
+/// assert!(HasAt::check("user").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! contains_check {
+    ($(#[$meta:meta])* $name:ident, $needle:expr $(,)*) => {
+        $(#[$meta])*
+        #[allow(missing_debug_implementations)]
+        pub enum $name {}
+
+        impl $crate::Check for $name {
+
+            type Error = $crate::check::ContainsError;
+
+            fn check(value: &str) -> Result<(), Self::Error> {
+                let needle: &'static str = $needle;
+                if value.contains(needle) {
+                    Ok(())
+                } else {
+                    Err($crate::check::ContainsError { needle })
+                }
+            }
+        }
+
+        impl $crate::BoundedBytes for $name {
+
+            const MAX: Option<usize> = None;
+        }
+    }
+}
+
+pub use contains_check;
+
+/// Signals that a value is invalid because one of its lines is too long.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineLengthError {
+    /// The maximum number of bytes allowed per line.
+    pub max: usize,
+    /// The zero-based index of the first line that is too long.
+    pub line_index: usize,
+    /// The number of bytes in the line that is too long.
+    pub len: usize,
+}
+
+impl error::Error for LineLengthError {
+
+    fn description(&self) -> &str { "LineLength error" }
+}
+
+impl fmt::Display for LineLengthError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "value contains line {} with {} bytes which exceeds the limit of {} bytes",
+            self.line_index,
+            self.len,
+            self.max,
+        )
+    }
+}
+
+impl ::CheckErrorCode for LineLengthError {
+
+    fn code(&self) -> &'static str { "line_length" }
+}
+
+/// Define a check limiting the byte length of each line.
+///
+/// The generated check splits the value on `\n` and fails with a `LineLengthError` for the
+/// first line longer than the given number of bytes. The limit can be given as either
+/// `Name, limit` or `Name = limit`. A `\r` before the line break counts
+/// towards the line length.
+///
+/// The limit applies per line, not to the whole value. Combine the check with one of the
+/// `MaxBytes` checks to limit the total length as well, or use `SingleLine` when the value
+/// may only have a single line.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// line_length_check!(
+///     /// Ensure no line is longer than 8 bytes.
+///     Wrap8, 8
+/// );
+///
+/// line_length_check!(
+///     /// Ensure no line is longer than 80 bytes.
+///     Wrap80 = 80
+/// );
+///
+/// assert!(Wrap8::check("foo bar\nbaz qux").is_ok());
+/// assert!(Wrap8::check("foo\nbar baz qux").is_err());
+/// assert!(Wrap80::check("foo\nbar baz qux").is_ok());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! line_length_check {
+    ($(#[$meta:meta])* $name:ident = $max:expr) => {
+        $crate::line_length_check!($(#[$meta])* $name, $max);
+    };
+    ($(#[$meta:meta])* $name:ident, $max:expr $(,)*) => {
+        $(#[$meta])*
+        #[allow(missing_debug_implementations)]
+        pub enum $name {}
+
+        impl $crate::Check for $name {
+
+            type Error = $crate::check::LineLengthError;
+
+            fn check(value: &str) -> Result<(), Self::Error> {
+                let max: usize = $max;
+                for (line_index, line) in value.split('\n').enumerate() {
+                    if line.len() > max {
+                        return Err($crate::check::LineLengthError {
+                            max,
+                            line_index,
+                            len: line.len(),
+                        });
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        impl $crate::BoundedBytes for $name {
+
+            const MAX: Option<usize> = None;
+        }
+    }
+}
+
+pub use line_length_check;
+
+/// Signals that a value does not have the expected number of characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExactCharsError {
+    /// The number of characters a valid value has.
+    pub expected: usize,
+    /// The number of characters in the value.
+    pub count: usize,
+}
+
+impl error::Error for ExactCharsError {
+
+    fn description(&self) -> &str { "ExactChars error" }
+}
+
+impl fmt::Display for ExactCharsError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "value has {} characters instead of {}",
+            self.count,
+            self.expected,
+        )
+    }
+}
+
+impl ::CheckErrorCode for ExactCharsError {
+
+    fn code(&self) -> &'static str { "exact_chars" }
+}
+
+/// Define a check requiring an exact number of characters.
+///
+/// The generated check counts the `char`s of the value and fails with an `ExactCharsError`
+/// when the count differs from the given one. The count can be given as either
+/// `Name, count` or `Name = count`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// exact_chars_check!(
+///     /// Ensure the value has exactly 4 characters.
+///     ExactlyFour, 4
+/// );
+///
+/// assert!(ExactlyFour::check("ABCD").is_ok());
+/// assert!(ExactlyFour::check("ÄÖÜß").is_ok());
+/// assert!(ExactlyFour::check("ABC").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! exact_chars_check {
+    ($(#[$meta:meta])* $name:ident = $expected:expr) => {
+        $crate::exact_chars_check!($(#[$meta])* $name, $expected);
+    };
+    ($(#[$meta:meta])* $name:ident, $expected:expr $(,)*) => {
+        $(#[$meta])*
+        #[allow(missing_debug_implementations)]
+        pub enum $name {}
+
+        impl $crate::Check for $name {
+
+            type Error = $crate::check::ExactCharsError;
+
+            fn check(value: &str) -> Result<(), Self::Error> {
+                let expected: usize = $expected;
+                let count = value.chars().count();
+                if count != expected {
+                    return Err($crate::check::ExactCharsError { expected, count });
+                }
+                Ok(())
+            }
+        }
+
+        impl $crate::BoundedBytes for $name {
+
+            const MAX: Option<usize> = None;
+        }
+    }
+}
+
+pub use exact_chars_check;
+
+/// Signals that a character occurs too often or too rarely in a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OccurrenceError {
+    /// The counted character.
+    pub found: char,
+    /// The number of occurrences in the value.
+    pub count: usize,
+    /// The minimum number of occurrences.
+    pub min: usize,
+    /// The maximum number of occurrences, or `None` if there is no limit.
+    pub max: Option<usize>,
+}
+
+impl error::Error for OccurrenceError {
+
+    fn description(&self) -> &str { "Occurrence error" }
+}
+
+impl fmt::Display for OccurrenceError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "value contains `{}` {} time(s) instead of ",
+            self.found.escape_default(),
+            self.count,
+        )?;
+        match self.max {
+            Some(max) if max == self.min => write!(fmt, "exactly {}", max),
+            Some(max) if self.min == 0 => write!(fmt, "at most {}", max),
+            Some(max) => write!(fmt, "{} to {}", self.min, max),
+            None => write!(fmt, "at least {}", self.min),
+        }
+    }
+}
+
+impl ::CheckErrorCode for OccurrenceError {
+
+    fn code(&self) -> &'static str { "occurrence" }
+}
+
+/// Check the number of occurrences of a character in a value.
+///
+/// This is used by the checks generated by `occurrence_check!`.
+#[doc(hidden)]
+pub fn check_occurrences(
+    value: &str,
+    found: char,
+    min: usize,
+    max: Option<usize>,
+) -> Result<(), OccurrenceError> {
+    let count = value.chars().filter(|c| *c == found).count();
+    if count < min || max.is_some_and(|max| count > max) {
+        return Err(OccurrenceError { found, count, min, max });
+    }
+    Ok(())
+}
+
+/// Define a check counting the occurrences of a character.
+///
+/// The generated check fails with an `OccurrenceError` when the character doesn't occur the
+/// required number of times. The count can be given as `count` for exactly that many
+/// occurrences, or as `at_most count` or `at_least count`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// occurrence_check!(
+///     /// Ensure a value contains exactly one `@`.
+///     ExactlyOneAt, '@', 1
+/// );
+///
+/// occurrence_check!(
+///     /// Ensure a value contains at most two `.`.
+///     AtMostTwoDots, '.', at_most 2
+/// );
+///
+/// occurrence_check!(
+///     /// Ensure a value contains at least one `/`.
+///     AtLeastOneSlash, '/', at_least 1
+/// );
+///
+/// assert!(ExactlyOneAt::check("user@example.com").is_ok());
+/// assert!(ExactlyOneAt::check("user@@example.com").is_err());
+/// assert!(AtMostTwoDots::check("1.2.3").is_ok());
+/// assert!(AtMostTwoDots::check("1.2.3.4").is_err());
+/// assert!(AtLeastOneSlash::check("a/b/c").is_ok());
+/// assert!(AtLeastOneSlash::check("abc").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! occurrence_check {
+    ($(#[$meta:meta])* $name:ident, $found:expr, at_most $max:expr $(,)*) => {
+        $crate::occurrence_check!(@impl $(#[$meta])* $name, $found, 0, Some($max));
+    };
+    ($(#[$meta:meta])* $name:ident, $found:expr, at_least $min:expr $(,)*) => {
+        $crate::occurrence_check!(@impl $(#[$meta])* $name, $found, $min, None);
+    };
+    ($(#[$meta:meta])* $name:ident, $found:expr, $count:expr $(,)*) => {
+        $crate::occurrence_check!(@impl $(#[$meta])* $name, $found, $count, Some($count));
+    };
+    (@impl $(#[$meta:meta])* $name:ident, $found:expr, $min:expr, $max:expr) => {
+        $(#[$meta])*
+        #[allow(missing_debug_implementations)]
+        pub enum $name {}
+
+        impl $crate::Check for $name {
+
+            type Error = $crate::check::OccurrenceError;
+
+            fn check(value: &str) -> Result<(), Self::Error> {
+                $crate::check::check_occurrences(value, $found, $min, $max)
+            }
+        }
+
+        impl $crate::BoundedBytes for $name {
+
+            const MAX: Option<usize> = None;
+        }
+    };
+}
+
+pub use occurrence_check;
+
+/// Signals that a value is not one of a set of allowed values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OneOfError {
+    /// The canonical allowed values.
+    pub values: &'static [&'static str],
+}
+
+impl error::Error for OneOfError {
+
+    fn description(&self) -> &str { "OneOf error" }
+}
+
+impl fmt::Display for OneOfError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "value is not one of")?;
+        for (index, value) in self.values.iter().enumerate() {
+            let separator = if index == 0 { " " } else { ", " };
+            write!(fmt, "{}`{}`", separator, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl ::CheckErrorCode for OneOfError {
+
+    fn code(&self) -> &'static str { "one_of" }
+}
+
+/// Define a check requiring a value to be one of a set of keywords, ignoring ASCII case.
+///
+/// The generated check fails with a `OneOfError` listing the canonical values when the value
+/// doesn't match any of them.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// case_insensitive_one_of!(
+///     /// Ensure a value is a boolean keyword.
+///     Bool, "true", "false"
+/// );
+///
+/// assert!(Bool::check("true").is_ok());
+/// assert!(Bool::check("FALSE").is_ok());
+/// assert!(Bool::check("yes").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! case_insensitive_one_of {
+    ($(#[$meta:meta])* $name:ident, $($value:expr),+ $(,)*) => {
+        $(#[$meta])*
+        #[allow(missing_debug_implementations)]
+        pub enum $name {}
+
+        impl $crate::Check for $name {
+
+            type Error = $crate::check::OneOfError;
+
+            fn check(value: &str) -> Result<(), Self::Error> {
+                const VALUES: &[&str] = &[$($value),+];
+                if VALUES.iter().any(|allowed| allowed.eq_ignore_ascii_case(value)) {
+                    Ok(())
+                } else {
+                    Err($crate::check::OneOfError { values: VALUES })
+                }
+            }
+        }
+
+        impl $crate::BoundedBytes for $name {
+
+            const MAX: Option<usize> = None;
+        }
+    }
+}
+
+pub use case_insensitive_one_of;
+
+/// Signals that a value is one of a set of forbidden values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotOneOfError {
+    /// The forbidden value that was matched.
+    pub matched: &'static str,
+}
+
+impl error::Error for NotOneOfError {
+
+    fn description(&self) -> &str { "NotOneOf error" }
+}
+
+impl fmt::Display for NotOneOfError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "value `{}` is not allowed", self.matched)
+    }
+}
+
+impl ::CheckErrorCode for NotOneOfError {
+
+    fn code(&self) -> &'static str { "not_one_of" }
+}
+
+/// Check a value against a list of forbidden values.
+///
+/// This is used by the checks generated by `not_one_of!`.
+#[doc(hidden)]
+pub fn check_not_one_of(
+    value: &str,
+    forbidden: &'static [&'static str],
+    ignore_ascii_case: bool,
+) -> Result<(), NotOneOfError> {
+    let found = forbidden.iter().find(|candidate| {
+        if ignore_ascii_case {
+            candidate.eq_ignore_ascii_case(value)
+        } else {
+            **candidate == value
+        }
+    });
+    match found {
+        Some(&matched) => Err(NotOneOfError { matched }),
+        None => Ok(()),
+    }
+}
+
+/// Define a check rejecting a set of forbidden values.
+///
+/// The generated check fails with a `NotOneOfError` when the value equals one of the given
+/// values. Passing `ignore_ascii_case` before the values makes the comparison ignore ASCII
+/// case. Combine it with other checks via `And` to reject reserved words.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// not_one_of!(
+///     /// Reject reserved user names.
+///     Reserved, "admin", "root", "system"
+/// );
+///
+/// not_one_of!(
+///     /// Reject reserved user names in any case.
+///     ReservedAnyCase, ignore_ascii_case, "admin", "root", "system"
+/// );
+///
+/// assert!(Reserved::check("alice").is_ok());
+/// assert!(Reserved::check("root").is_err());
+/// assert!(Reserved::check("Root").is_ok());
+/// assert!(ReservedAnyCase::check("Root").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! not_one_of {
+    ($(#[$meta:meta])* $name:ident, ignore_ascii_case, $($value:expr),+ $(,)*) => {
+        $crate::not_one_of!(@impl $(#[$meta])* $name, true, $($value),+);
+    };
+    ($(#[$meta:meta])* $name:ident, $($value:expr),+ $(,)*) => {
+        $crate::not_one_of!(@impl $(#[$meta])* $name, false, $($value),+);
+    };
+    (@impl $(#[$meta:meta])* $name:ident, $ignore_case:expr, $($value:expr),+) => {
+        $(#[$meta])*
+        #[allow(missing_debug_implementations)]
+        pub enum $name {}
+
+        impl $crate::Check for $name {
+
+            type Error = $crate::check::NotOneOfError;
+
+            fn check(value: &str) -> Result<(), Self::Error> {
+                $crate::check::check_not_one_of(value, &[$($value),+], $ignore_case)
+            }
+        }
+
+        impl $crate::BoundedBytes for $name {
+
+            const MAX: Option<usize> = None;
+        }
+    };
+}
+
+pub use not_one_of;
+
+/// Signals that a value is a reserved word.
+///
+/// This is the error of the checks generated by `not_equal_check!` and `not_equal_check_ci!`.
+pub type ReservedWordError = NotOneOfError;
+
+/// Define a check rejecting reserved words.
+///
+/// This is an alternative syntax for `not_one_of!`. Matching is exact and case-sensitive, use
+/// `not_equal_check_ci!` to ignore ASCII case. The generated check fails with a
+/// `ReservedWordError` naming the matched word.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+/// use textkind::check::{And, Identifier};
+///
+/// not_equal_check!(
+///     /// Reject path keywords.
+///     NotKeyword = ["self", "super", "crate"]
+/// );
+///
+/// type PathSegment = And<Identifier, NotKeyword>;
+///
+/// assert!(PathSegment::check("module").is_ok());
+/// assert!(PathSegment::check("Self").is_ok());
+/// assert!(PathSegment::check("super").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! not_equal_check {
+    ($(#[$meta:meta])* $name:ident = [$($value:expr),+ $(,)*]) => {
+        $crate::not_one_of!($(#[$meta])* $name, $($value),+);
+    }
+}
+
+pub use not_equal_check;
+
+/// Define a check rejecting reserved words, ignoring ASCII case.
+///
+/// This behaves like `not_equal_check!` but also rejects the reserved words with different
+/// ASCII case.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// not_equal_check_ci!(
+///     /// Reject path keywords in any case.
+///     NotKeyword = ["self", "super", "crate"]
+/// );
+///
+/// assert!(NotKeyword::check("module").is_ok());
+/// assert!(NotKeyword::check("Self").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! not_equal_check_ci {
+    ($(#[$meta:meta])* $name:ident = [$($value:expr),+ $(,)*]) => {
+        $crate::not_one_of!($(#[$meta])* $name, ignore_ascii_case, $($value),+);
+    }
+}
+
+pub use not_equal_check_ci;
+
+/// Signals that a value contains a character outside of an allowed set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllowedCharsError {
+    /// The first character that is not allowed.
+    pub found: char,
+    /// The byte index of the character in the value.
+    pub index: usize,
+}
+
+impl error::Error for AllowedCharsError {
+
+    fn description(&self) -> &str { "AllowedChars error" }
+}
+
+impl fmt::Display for AllowedCharsError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "value contains disallowed character `{}` at index {}",
+            self.found.escape_default(),
+            self.index,
+        )
+    }
+}
+
+impl ::CheckErrorCode for AllowedCharsError {
+
+    fn code(&self) -> &'static str { "allowed_chars" }
+}
+
+/// Define a check only allowing characters from a set of patterns.
+///
+/// The set is given as a list of `char` patterns, which can be single characters or
+/// inclusive ranges. The generated check fails with an `AllowedCharsError` on the first
+/// character that doesn't match any of them. Empty values are accepted.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// allowed_chars_check!(
+///     /// Ensure a value only contains lowercase letters, digits, `_` and `.`.
+///     ModulePath = ['a'..='z', '0'..='9', '_', '.']
+/// );
+///
+/// assert!(ModulePath::check("std.fmt_2").is_ok());
+/// assert!(ModulePath::check("std::fmt").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! allowed_chars_check {
+    ($(#[$meta:meta])* $name:ident = [$($pattern:pat),+ $(,)*]) => {
+        $(#[$meta])*
+        #[allow(missing_debug_implementations)]
+        pub enum $name {}
+
+        impl $crate::Check for $name {
+
+            type Error = $crate::check::AllowedCharsError;
+
+            fn check(value: &str) -> Result<(), Self::Error> {
+                for (index, found) in value.char_indices() {
+                    match found {
+                        $($pattern)|+ => (),
+                        _ => return Err($crate::check::AllowedCharsError { found, index }),
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        impl $crate::BoundedBytes for $name {
+
+            const MAX: Option<usize> = None;
+        }
+    }
+}
+
+pub use allowed_chars_check;
+
+/// Signals that a value contains a character outside of a character set.
+///
+/// This is the error of the checks generated by `char_set!`. It is the same type as
+/// `AllowedCharsError`, with the character in `found` and its byte index in `index`.
+pub type CharSetError = AllowedCharsError;
+
+/// Define a check only allowing characters from a character set.
+///
+/// This is an alternative syntax for `allowed_chars_check!`, taking the patterns directly
+/// after the name. The generated check fails on the first character that doesn't match any
+/// of them, with a `CharSetError`. That is an alias of `AllowedCharsError`, whose `found`
+/// field holds the character and whose `index` field holds its byte index. Empty values are
+/// accepted.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+/// use textkind::check::{And, NotEmpty};
+///
+/// char_set!(
+///     /// Allow the characters of a lax identifier.
+///     LaxChars, 'a'..='z', 'A'..='Z', '0'..='9', '_'
+/// );
+///
+/// type MyIdentifierLax = And<NotEmpty, LaxChars>;
+///
+/// assert!(MyIdentifierLax::check("23foo_bar").is_ok());
+/// assert!(MyIdentifierLax::check("foo-bar").is_err());
+///
+/// let error = LaxChars::check("foo-bar").unwrap_err();
+/// assert_eq!(error.found, '-');
+/// assert_eq!(error.index, 3);
+/// assert!(MyIdentifierLax::check("").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! char_set {
+    ($(#[$meta:meta])* $name:ident, $($pattern:pat),+ $(,)*) => {
+        $crate::allowed_chars_check!($(#[$meta])* $name = [$($pattern),+]);
+    }
+}
+
+pub use char_set;
+
+/// Define a check converting the errors of another check.
+///
+/// The generated check runs the inner check and converts its errors into the given error type
+/// via `From`. This allows a kind to expose a single domain specific error type instead of
+/// the structure of the checks it is composed of.
+///
+/// The generated check forwards the `BoundedBytes` bound of the inner check, so the inner
+/// check has to implement `BoundedBytes`. All predefined checks do.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+/// use textkind::check::{Identifier, IdentifierError};
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum FieldError {
+///     InvalidName(IdentifierError),
+/// }
+///
+/// impl From<IdentifierError> for FieldError {
+///     fn from(error: IdentifierError) -> Self { FieldError::InvalidName(error) }
+/// }
+///
+/// map_err_check!(
+///     /// Ensure a value is a valid field name.
+///     FieldName = Identifier => FieldError
+/// );
+///
+/// assert!(FieldName::check("foo").is_ok());
+/// assert_eq!(
+///     FieldName::check("2foo"),
+///     Err(FieldError::InvalidName(IdentifierError::InvalidStartChar('2'))),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! map_err_check {
+    ($(#[$meta:meta])* $name:ident = $inner:ty => $error:ty $(,)*) => {
+        $(#[$meta])*
+        #[allow(missing_debug_implementations)]
+        pub enum $name {}
+
+        impl $crate::Check for $name {
+
+            type Error = $error;
+
+            fn check(value: &str) -> Result<(), Self::Error> {
+                <$inner as $crate::Check>::check(value).map_err(<$error>::from)
+            }
+        }
+
+        impl $crate::BoundedBytes for $name {
+
+            const MAX: Option<usize> = <$inner as $crate::BoundedBytes>::MAX;
+        }
+    }
+}
+
+pub use map_err_check;
+
+/// Signals that a value is invalid because it contains a bidirectional control character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BidiControlError {
+    /// The first bidirectional control character that was found.
+    pub found: char,
+    /// The byte index of the character in the value.
+    pub index: usize,
+}
+
+impl error::Error for BidiControlError {
+
+    fn description(&self) -> &str { "NoBidiControl error" }
+}
+
+impl fmt::Display for BidiControlError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "value contains bidirectional control character `{}` at index {}",
+            self.found.escape_unicode(),
+            self.index,
+        )
+    }
+}
+
+impl ::CheckErrorCode for BidiControlError {
+
+    fn code(&self) -> &'static str { "no_bidi_control" }
+}
+
+/// Ensure a value does not contain Unicode bidirectional control characters.
+///
+/// This rejects the embedding and override characters `U+202A` to `U+202E` and the isolate
+/// characters `U+2066` to `U+2069`. These can be used to make text display differently from
+/// its logical order.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::NoBidiControl::check("foo").is_ok());
+/// assert!(textkind::check::NoBidiControl::check("").is_ok());
+///
+/// assert!(textkind::check::NoBidiControl::check("foo\u{202E}bar").is_err());
+/// assert!(textkind::check::NoBidiControl::check("\u{2066}foo\u{2069}").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NoBidiControl {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for NoBidiControl {
+
+    type Error = BidiControlError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        let is_bidi_control = |c: char| {
+            ('\u{202A}'..='\u{202E}').contains(&c) || ('\u{2066}'..='\u{2069}').contains(&c)
+        };
+        match value.char_indices().find(|&(_, c)| is_bidi_control(c)) {
+            Some((index, found)) => Err(BidiControlError { found, index }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ::BoundedBytes for NoBidiControl {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Signals that a value is not a valid environment variable name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvVarNameError {
+    /// The value is empty.
+    Empty(NotEmptyError),
+    /// The value starts with an ASCII digit.
+    DigitStart(char),
+    /// The value contains an invalid character at the given byte index.
+    InvalidChar {
+        /// The invalid character.
+        found: char,
+        /// The byte index of the character in the value.
+        index: usize,
+    },
+    /// The value contains a lowercase character at the given byte index.
+    LowercaseChar {
+        /// The lowercase character.
+        found: char,
+        /// The byte index of the character in the value.
+        index: usize,
+    },
+}
+
+impl error::Error for EnvVarNameError {
+
+    fn description(&self) -> &str { "EnvVarName error" }
+}
+
+impl fmt::Display for EnvVarNameError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EnvVarNameError::Empty(ref error) =>
+                fmt::Display::fmt(error, fmt),
+            EnvVarNameError::DigitStart(c) =>
+                write!(fmt, "value starts with digit `{}`", c),
+            EnvVarNameError::InvalidChar { found, index } =>
+                write!(
+                    fmt,
+                    "value contains invalid character `{}` at index {}",
+                    found.escape_default(),
+                    index,
+                ),
+            EnvVarNameError::LowercaseChar { found, index } =>
+                write!(fmt, "value contains lowercase character `{}` at index {}", found, index),
+        }
+    }
+}
+
+impl ::CheckErrorCode for EnvVarNameError {
+
+    fn code(&self) -> &'static str {
+        match *self {
+            EnvVarNameError::Empty(ref error) => error.code(),
+            EnvVarNameError::DigitStart(_) => "env_var_name_digit_start",
+            EnvVarNameError::InvalidChar { .. } => "env_var_name_char",
+            EnvVarNameError::LowercaseChar { .. } => "env_var_name_lowercase",
+        }
+    }
+}
+
+fn check_env_var_name(value: &str, uppercase: bool) -> Result<(), EnvVarNameError> {
+    <NotEmpty as ::Check>::check(value).map_err(EnvVarNameError::Empty)?;
+    for (index, c) in value.char_indices() {
+        match c {
+            '0'..='9' if index == 0 => return Err(EnvVarNameError::DigitStart(c)),
+            'a'..='z' if uppercase => return Err(EnvVarNameError::LowercaseChar {
+                found: c,
+                index,
+            }),
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' => (),
+            _ => return Err(EnvVarNameError::InvalidChar { found: c, index }),
+        }
+    }
+    Ok(())
+}
+
+/// Ensure a value is a valid environment variable name.
+///
+/// To be a valid environment variable name, a value has to be not empty, must not start with
+/// an ASCII digit and may only contain the following characters:
+///
+/// * `A` to `Z` (uppercase ASCII alphabetic characters)
+/// * `a` to `z` (lowercase ASCII alphabetic characters)
+/// * `0` to `9` (ASCII digits)
+/// * `_` (underscore)
+///
+/// See `EnvVarNameUpper` for a check that also enforces the uppercase convention.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::EnvVarName::check("PATH").is_ok());
+/// assert!(textkind::check::EnvVarName::check("my_var2").is_ok());
+///
+/// assert!(textkind::check::EnvVarName::check("2VAR").is_err());
+/// assert!(textkind::check::EnvVarName::check("my-var").is_err());
+/// assert!(textkind::check::EnvVarName::check("").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct EnvVarName {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for EnvVarName {
+
+    type Error = EnvVarNameError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        check_env_var_name(value, false)
+    }
+}
+
+impl ::BoundedBytes for EnvVarName {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Ensure a value is a valid uppercase environment variable name.
+///
+/// This is the same as `EnvVarName` but also rejects lowercase ASCII alphabetic characters.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::EnvVarNameUpper::check("MY_VAR2").is_ok());
+///
+/// assert!(textkind::check::EnvVarNameUpper::check("My_Var").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct EnvVarNameUpper {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for EnvVarNameUpper {
+
+    type Error = EnvVarNameError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        check_env_var_name(value, true)
+    }
+}
+
+impl ::BoundedBytes for EnvVarNameUpper {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Signals that a value is invalid because it contains a byte outside of printable ASCII.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AsciiPrintableError {
+    /// The first byte that is not printable ASCII.
+    pub byte: u8,
+    /// The index of the byte in the value.
+    pub index: usize,
+}
+
+impl error::Error for AsciiPrintableError {
+
+    fn description(&self) -> &str { "AsciiPrintable error" }
+}
+
+impl fmt::Display for AsciiPrintableError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "value contains byte 0x{:02x} at index {} that is not printable ASCII",
+            self.byte,
+            self.index,
+        )
+    }
+}
+
+impl ::CheckErrorCode for AsciiPrintableError {
+
+    fn code(&self) -> &'static str { "ascii_printable" }
+}
+
+/// Ensure a value only contains printable ASCII characters.
+///
+/// Printable ASCII characters are the bytes `0x20` (space) to `0x7E` (`~`). This rejects
+/// control characters like tabs and line breaks, `DEL` and all non-ASCII characters. For
+/// non-ASCII characters the error reports the first byte of the encoded character.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::AsciiPrintable::check("Foo Bar 23!").is_ok());
+/// assert!(textkind::check::AsciiPrintable::check("").is_ok());
+///
+/// assert!(textkind::check::AsciiPrintable::check("foo\tbar").is_err());
+/// assert!(textkind::check::AsciiPrintable::check("Käse").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct AsciiPrintable {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for AsciiPrintable {
+
+    type Error = AsciiPrintableError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        match value.bytes().position(|b| !(0x20..=0x7e).contains(&b)) {
+            Some(index) => Err(AsciiPrintableError { byte: value.as_bytes()[index], index }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ::BoundedBytes for AsciiPrintable {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Signals that a value is not a valid tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagError {
+    /// The value is empty.
+    Empty(NotEmptyError),
+    /// The value contains an invalid character at the given byte index.
+    InvalidChar {
+        /// The invalid character.
+        found: char,
+        /// The byte index of the character in the value.
+        index: usize,
+    },
+    /// The value starts with a hyphen.
+    LeadingHyphen,
+    /// The value ends with a hyphen.
+    TrailingHyphen,
+}
+
+impl error::Error for TagError {
+
+    fn description(&self) -> &str { "Tag error" }
+}
+
+impl fmt::Display for TagError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TagError::Empty(ref error) =>
+                fmt::Display::fmt(error, fmt),
+            TagError::InvalidChar { found, index } =>
+                write!(
+                    fmt,
+                    "value contains invalid character `{}` at index {}",
+                    found.escape_default(),
+                    index,
+                ),
+            TagError::LeadingHyphen =>
+                write!(fmt, "value starts with a hyphen"),
+            TagError::TrailingHyphen =>
+                write!(fmt, "value ends with a hyphen"),
+        }
+    }
+}
+
+impl ::CheckErrorCode for TagError {
+
+    fn code(&self) -> &'static str {
+        match *self {
+            TagError::Empty(ref error) => error.code(),
+            TagError::InvalidChar { .. } => "tag_char",
+            TagError::LeadingHyphen => "tag_leading_hyphen",
+            TagError::TrailingHyphen => "tag_trailing_hyphen",
+        }
+    }
+}
+
+/// Ensure a value is a valid tag.
+///
+/// To be a valid tag, a value has to be not empty and only contain the following
+/// characters:
+///
+/// * `a` to `z` (lowercase ASCII alphabetic characters)
+/// * `0` to `9` (ASCII digits)
+/// * `-` (hyphen), but not at the beginning or end
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::Tag::check("rust-lang").is_ok());
+/// assert!(textkind::check::Tag::check("2018").is_ok());
+///
+/// assert!(textkind::check::Tag::check("Rust").is_err());
+/// assert!(textkind::check::Tag::check("-x").is_err());
+/// assert!(textkind::check::Tag::check("rust.lang").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tag {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for Tag {
+
+    type Error = TagError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        <NotEmpty as ::Check>::check(value).map_err(TagError::Empty)?;
+        for (index, c) in value.char_indices() {
+            match c {
+                'a'..='z' | '0'..='9' | '-' => (),
+                _ => return Err(TagError::InvalidChar { found: c, index }),
+            }
+        }
+        if value.starts_with('-') {
+            Err(TagError::LeadingHyphen)
+        } else if value.ends_with('-') {
+            Err(TagError::TrailingHyphen)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl ::BoundedBytes for Tag {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Signals that a value is not a valid JSON Pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonPointerError {
+    /// The value is not empty but doesn't start with a `/`.
+    MissingLeadingSlash,
+    /// A reference token contains an invalid `~` escape.
+    InvalidEscape {
+        /// The index of the reference token containing the escape.
+        token: usize,
+        /// The byte index of the `~` in the value.
+        index: usize,
+    },
+}
+
+impl error::Error for JsonPointerError {
+
+    fn description(&self) -> &str { "JsonPointer error" }
+}
+
+impl fmt::Display for JsonPointerError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JsonPointerError::MissingLeadingSlash =>
+                write!(fmt, "value does not start with a slash"),
+            JsonPointerError::InvalidEscape { token, index } =>
+                write!(
+                    fmt,
+                    "reference token {} contains an invalid escape at index {}",
+                    token,
+                    index,
+                ),
+        }
+    }
+}
+
+impl ::CheckErrorCode for JsonPointerError {
+
+    fn code(&self) -> &'static str {
+        match *self {
+            JsonPointerError::MissingLeadingSlash => "json_pointer_missing_slash",
+            JsonPointerError::InvalidEscape { .. } => "json_pointer_invalid_escape",
+        }
+    }
+}
+
+/// Ensure a value is a valid JSON Pointer as described in RFC 6901.
+///
+/// The empty value is valid and refers to the whole document. Every other value has to
+/// start with a `/`, and every `~` in a reference token has to be followed by `0` or `1`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::JsonPointer::check("").is_ok());
+/// assert!(textkind::check::JsonPointer::check("/foo/0").is_ok());
+/// assert!(textkind::check::JsonPointer::check("/a~1b").is_ok());
+///
+/// assert!(textkind::check::JsonPointer::check("foo").is_err());
+/// assert!(textkind::check::JsonPointer::check("/a~2").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct JsonPointer {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for JsonPointer {
+
+    type Error = JsonPointerError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        if value.is_empty() {
+            return Ok(());
+        }
+        if !value.starts_with('/') {
+            return Err(JsonPointerError::MissingLeadingSlash);
+        }
+        let mut token = 0;
+        let mut bytes = value.bytes().enumerate().skip(1);
+        while let Some((index, byte)) = bytes.next() {
+            match byte {
+                b'/' => token += 1,
+                b'~' => match bytes.next() {
+                    Some((_, b'0')) | Some((_, b'1')) => (),
+                    _ => return Err(JsonPointerError::InvalidEscape { token, index }),
+                },
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ::BoundedBytes for JsonPointer {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Signals that a value contains a NUL character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NulError {
+    /// The byte index of the first NUL character in the value.
+    pub index: usize,
+}
+
+impl error::Error for NulError {
+
+    fn description(&self) -> &str { "NoNul error" }
+}
+
+impl fmt::Display for NulError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "value contains a NUL character at index {}", self.index)
+    }
+}
+
+impl ::CheckErrorCode for NulError {
+
+    fn code(&self) -> &'static str { "no_nul" }
+}
+
+/// Ensure a value doesn't contain any NUL (`\0`) characters.
+///
+/// Values passing this check can be turned into a `std::ffi::CString`. Unlike `NoControl`,
+/// other control characters like tabs are allowed.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::NoNul::check("foo\tbar").is_ok());
+///
+/// assert!(textkind::check::NoNul::check("foo\0bar").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NoNul {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for NoNul {
+
+    type Error = NulError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        match value.bytes().position(|b| b == 0) {
+            Some(index) => Err(NulError { index }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ::BoundedBytes for NoNul {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Signals that a value is not a valid decimal number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalError {
+    /// The value has no digits before the decimal point.
+    MissingIntegerDigits,
+    /// The value has a decimal point without digits following it.
+    MissingFractionDigits,
+    /// The integer part has a leading zero.
+    LeadingZero,
+    /// The value contains more than one decimal point.
+    MultiplePoints,
+    /// The value contains an invalid character at the given byte index.
+    InvalidChar {
+        /// The invalid character.
+        found: char,
+        /// The byte index of the character in the value.
+        index: usize,
+    },
+    /// The value has too many fractional digits.
+    TooManyFractionDigits {
+        /// The maximum number of fractional digits.
+        max: usize,
+        /// The number of fractional digits in the value.
+        len: usize,
+    },
+}
+
+impl error::Error for DecimalError {
+
+    fn description(&self) -> &str { "Decimal error" }
+}
+
+impl fmt::Display for DecimalError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecimalError::MissingIntegerDigits =>
+                write!(fmt, "value has no digits before the decimal point"),
+            DecimalError::MissingFractionDigits =>
+                write!(fmt, "value has no digits after the decimal point"),
+            DecimalError::LeadingZero =>
+                write!(fmt, "value has a leading zero"),
+            DecimalError::MultiplePoints =>
+                write!(fmt, "value contains more than one decimal point"),
+            DecimalError::InvalidChar { found, index } =>
+                write!(
+                    fmt,
+                    "value contains invalid character `{}` at index {}",
+                    found.escape_default(),
+                    index,
+                ),
+            DecimalError::TooManyFractionDigits { max, len } =>
+                write!(fmt, "value has {} fractional digits, at most {} are allowed", len, max),
+        }
+    }
+}
+
+impl ::CheckErrorCode for DecimalError {
+
+    fn code(&self) -> &'static str {
+        match *self {
+            DecimalError::MissingIntegerDigits => "decimal_missing_integer_digits",
+            DecimalError::MissingFractionDigits => "decimal_missing_fraction_digits",
+            DecimalError::LeadingZero => "decimal_leading_zero",
+            DecimalError::MultiplePoints => "decimal_multiple_points",
+            DecimalError::InvalidChar { .. } => "decimal_char",
+            DecimalError::TooManyFractionDigits { .. } => "decimal_fraction_digits",
+        }
+    }
+}
+
+/// Check a value for a decimal number with an optional fractional digit limit.
+///
+/// This is used by `Decimal` and the checks generated by `decimal_check!`.
+#[doc(hidden)]
+pub fn check_decimal(value: &str, max_fraction: Option<usize>) -> Result<(), DecimalError> {
+    let offset = if value.starts_with('-') { 1 } else { 0 };
+    let unsigned = &value[offset..];
+    let (integer, fraction) = match unsigned.find('.') {
+        Some(point) => (&unsigned[..point], Some((point + 1, &unsigned[point + 1..]))),
+        None => (unsigned, None),
+    };
+    let check_digits = |start: usize, digits: &str| {
+        match digits.char_indices().find(|&(_, c)| !c.is_ascii_digit()) {
+            Some((_, '.')) => Err(DecimalError::MultiplePoints),
+            Some((index, found)) => Err(DecimalError::InvalidChar {
+                found,
+                index: offset + start + index,
+            }),
+            None => Ok(()),
+        }
+    };
+    check_digits(0, integer)?;
+    if integer.is_empty() {
+        return Err(DecimalError::MissingIntegerDigits);
+    }
+    if integer.len() > 1 && integer.starts_with('0') {
+        return Err(DecimalError::LeadingZero);
+    }
+    if let Some((start, fraction)) = fraction {
+        check_digits(start, fraction)?;
+        if fraction.is_empty() {
+            return Err(DecimalError::MissingFractionDigits);
+        }
+        if let Some(max) = max_fraction {
+            if fraction.len() > max {
+                return Err(DecimalError::TooManyFractionDigits { max, len: fraction.len() });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Ensure a value is a decimal number.
+///
+/// A decimal number consists of an optional leading `-`, ASCII digits and an optional
+/// decimal point followed by at least one more digit. The integer part must not have leading
+/// zeros, except for a single `0`. Exponents and a leading `+` are not allowed.
+///
+/// Use `decimal_check!` to define a check limiting the number of fractional digits.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::Decimal::check("0").is_ok());
+/// assert!(textkind::check::Decimal::check("-3.14").is_ok());
+/// assert!(textkind::check::Decimal::check("100.00").is_ok());
+///
+/// assert!(textkind::check::Decimal::check("01").is_err());
+/// assert!(textkind::check::Decimal::check("1.").is_err());
+/// assert!(textkind::check::Decimal::check("1.2.3").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Decimal {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for Decimal {
+
+    type Error = DecimalError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        check_decimal(value, None)
+    }
+}
+
+impl ::BoundedBytes for Decimal {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Define a decimal number check with a fractional digit limit.
+///
+/// The generated check behaves like `Decimal` but fails with
+/// `DecimalError::TooManyFractionDigits` when the value has more than the given number of
+/// digits after the decimal point.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// decimal_check!(
+///     /// Ensure a value is an amount of cents.
+///     Amount, 2
+/// );
+///
+/// assert!(Amount::check("19.99").is_ok());
+/// assert!(Amount::check("19").is_ok());
+/// assert!(Amount::check("19.999").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! decimal_check {
+    ($(#[$meta:meta])* $name:ident, $max_fraction:expr $(,)*) => {
+        $(#[$meta])*
+        #[allow(missing_debug_implementations)]
+        pub enum $name {}
+
+        impl $crate::Check for $name {
+
+            type Error = $crate::check::DecimalError;
+
+            fn check(value: &str) -> Result<(), Self::Error> {
+                $crate::check::check_decimal(value, Some($max_fraction))
+            }
+        }
+
+        impl $crate::BoundedBytes for $name {
+
+            const MAX: Option<usize> = None;
+        }
+    }
+}
+
+pub use decimal_check;
+
+/// Signals that a value is not a syntactically valid time zone name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TzNameError {
+    /// A slash separated component is empty.
+    EmptyComponent {
+        /// The zero-based index of the empty component.
+        component: usize,
+    },
+    /// The value contains an invalid character at the given byte index.
+    InvalidChar {
+        /// The invalid character.
+        found: char,
+        /// The byte index of the character in the value.
+        index: usize,
+    },
+}
+
+impl error::Error for TzNameError {
+
+    fn description(&self) -> &str { "TzName error" }
+}
+
+impl fmt::Display for TzNameError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TzNameError::EmptyComponent { component } =>
+                write!(fmt, "component {} of the value is empty", component),
+            TzNameError::InvalidChar { found, index } =>
+                write!(
+                    fmt,
+                    "value contains invalid character `{}` at index {}",
+                    found.escape_default(),
+                    index,
+                ),
+        }
+    }
+}
+
+impl ::CheckErrorCode for TzNameError {
+
+    fn code(&self) -> &'static str {
+        match *self {
+            TzNameError::EmptyComponent { .. } => "tz_name_empty_component",
+            TzNameError::InvalidChar { .. } => "tz_name_char",
+        }
+    }
+}
+
+/// Ensure a value has the structure of an IANA time zone name.
+///
+/// The value has to consist of one or more `/` separated components. Every component has to
+/// be non-empty and may only contain ASCII letters, ASCII digits, `_`, `-` and `+`.
+///
+/// This is a purely syntactic check. The value is not looked up in the time zone database, so
+/// a name like `Fake/Zone` is accepted.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::TzName::check("UTC").is_ok());
+/// assert!(textkind::check::TzName::check("America/Argentina/Buenos_Aires").is_ok());
+/// assert!(textkind::check::TzName::check("Etc/GMT+5").is_ok());
+///
+/// assert!(textkind::check::TzName::check("America//New_York").is_err());
+/// assert!(textkind::check::TzName::check("Europe/Berlin ").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TzName {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for TzName {
+
+    type Error = TzNameError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        let mut offset = 0;
+        for (component, part) in value.split('/').enumerate() {
+            if part.is_empty() {
+                return Err(TzNameError::EmptyComponent { component });
+            }
+            for (index, c) in part.char_indices() {
+                match c {
+                    'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '-' | '+' => (),
+                    _ => return Err(TzNameError::InvalidChar { found: c, index: offset + index }),
+                }
+            }
+            offset += part.len() + 1;
+        }
+        Ok(())
+    }
+}
+
+impl ::BoundedBytes for TzName {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Signals that a value is not whitespace normalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhitespaceNormalizedError {
+    /// The value starts with whitespace.
+    Leading,
+    /// The value ends with whitespace.
+    Trailing,
+    /// The value contains a run of whitespace characters.
+    Repeated {
+        /// The byte index of the second whitespace character in the run.
+        index: usize,
+    },
+    /// The value contains a whitespace character other than a space.
+    NonSpace {
+        /// The whitespace character.
+        found: char,
+        /// The byte index of the character in the value.
+        index: usize,
+    },
+}
+
+impl error::Error for WhitespaceNormalizedError {
+
+    fn description(&self) -> &str { "WhitespaceNormalized error" }
+}
+
+impl fmt::Display for WhitespaceNormalizedError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            WhitespaceNormalizedError::Leading =>
+                write!(fmt, "value starts with whitespace"),
+            WhitespaceNormalizedError::Trailing =>
+                write!(fmt, "value ends with whitespace"),
+            WhitespaceNormalizedError::Repeated { index } =>
+                write!(fmt, "value contains repeated whitespace at index {}", index),
+            WhitespaceNormalizedError::NonSpace { found, index } =>
+                write!(
+                    fmt,
+                    "value contains whitespace `{}` at index {} that is not a space",
+                    found.escape_default(),
+                    index,
+                ),
+        }
+    }
+}
+
+impl ::CheckErrorCode for WhitespaceNormalizedError {
+
+    fn code(&self) -> &'static str {
+        match *self {
+            WhitespaceNormalizedError::Leading => "whitespace_normalized_leading",
+            WhitespaceNormalizedError::Trailing => "whitespace_normalized_trailing",
+            WhitespaceNormalizedError::Repeated { .. } => "whitespace_normalized_repeated",
+            WhitespaceNormalizedError::NonSpace { .. } => "whitespace_normalized_non_space",
+        }
+    }
+}
+
+/// Ensure a value is whitespace normalized.
+///
+/// A normalized value has no leading or trailing whitespace, and words are separated by
+/// single spaces. This accepts exactly the values `Text::normalize_whitespace` produces, so
+/// it can be used to require normalized input instead of normalizing it.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::WhitespaceNormalized::check("foo bar").is_ok());
+///
+/// assert!(textkind::check::WhitespaceNormalized::check("foo  bar").is_err());
+/// assert!(textkind::check::WhitespaceNormalized::check(" foo").is_err());
+/// assert!(textkind::check::WhitespaceNormalized::check("foo\tbar").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct WhitespaceNormalized {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for WhitespaceNormalized {
+
+    type Error = WhitespaceNormalizedError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        if value.starts_with(char::is_whitespace) {
+            return Err(WhitespaceNormalizedError::Leading);
+        }
+        if value.ends_with(char::is_whitespace) {
+            return Err(WhitespaceNormalizedError::Trailing);
+        }
+        let mut previous_whitespace = false;
+        for (index, c) in value.char_indices() {
+            if !c.is_whitespace() {
+                previous_whitespace = false;
+                continue;
+            }
+            if previous_whitespace {
+                return Err(WhitespaceNormalizedError::Repeated { index });
+            }
+            if c != ' ' {
+                return Err(WhitespaceNormalizedError::NonSpace { found: c, index });
+            }
+            previous_whitespace = true;
+        }
+        Ok(())
+    }
+}
+
+impl ::BoundedBytes for WhitespaceNormalized {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Signals that a value does not start with an uppercase character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StartsWithUppercaseError {
+    /// The first non-whitespace character, or `None` if there is none.
+    pub found: Option<char>,
+}
+
+impl error::Error for StartsWithUppercaseError {
+
+    fn description(&self) -> &str { "StartsWithUppercase error" }
+}
+
+impl fmt::Display for StartsWithUppercaseError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.found {
+            Some(found) =>
+                write!(
+                    fmt,
+                    "value starts with `{}` instead of an uppercase character",
+                    found.escape_default(),
+                ),
+            None => write!(fmt, "value does not start with an uppercase character"),
+        }
+    }
+}
+
+impl ::CheckErrorCode for StartsWithUppercaseError {
+
+    fn code(&self) -> &'static str { "starts_with_uppercase" }
+}
+
+/// Ensure the first non-whitespace character of a value is uppercase.
+///
+/// Leading whitespace is skipped, use one of the `Trimmed*` checks to forbid it. Characters
+/// without case, like digits, are not uppercase. Values without a non-whitespace character
+/// are invalid.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// type TitleSentence = textkind::check::And<
+///     textkind::check::Title,
+///     textkind::check::StartsWithUppercase,
+/// >;
+///
+/// assert!(TitleSentence::check("Hello").is_ok());
+/// assert!(TitleSentence::check("Ärger").is_ok());
+///
+/// assert!(TitleSentence::check("hello").is_err());
+/// assert!(TitleSentence::check("").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct StartsWithUppercase {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for StartsWithUppercase {
+
+    type Error = StartsWithUppercaseError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        match value.trim_start().chars().next() {
+            Some(c) if c.is_uppercase() => Ok(()),
+            found => Err(StartsWithUppercaseError { found }),
+        }
+    }
+}
+
+impl ::BoundedBytes for StartsWithUppercase {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Signals that a value contains a character more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateCharError {
+    /// The repeated character.
+    pub found: char,
+    /// The byte index of the second occurrence of the character.
+    pub index: usize,
+}
+
+impl error::Error for DuplicateCharError {
+
+    fn description(&self) -> &str { "DuplicateChar error" }
+}
+
+impl fmt::Display for DuplicateCharError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "value repeats character `{}` at index {}",
+            self.found.escape_default(),
+            self.index,
+        )
+    }
+}
+
+impl ::CheckErrorCode for DuplicateCharError {
+
+    fn code(&self) -> &'static str { "no_duplicate_char" }
+}
+
+/// Ensure every character of a value appears at most once.
+///
+/// The first character that repeats an earlier one is reported. Characters are compared
+/// exactly, so `a` and `A` are distinct.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::NoDuplicateChar::check("abcd").is_ok());
+/// assert!(textkind::check::NoDuplicateChar::check("abca").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NoDuplicateChar {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for NoDuplicateChar {
+
+    type Error = DuplicateCharError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        let mut seen = collections::HashSet::new();
+        for (index, c) in value.char_indices() {
+            if !seen.insert(c) {
+                return Err(DuplicateCharError { found: c, index });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ::BoundedBytes for NoDuplicateChar {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Signals that a value is not a valid XML NCName.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NcNameError {
+    /// The value is empty.
+    Empty(NotEmptyError),
+    /// The value begins with an invalid character.
+    InvalidStartChar {
+        /// The invalid character.
+        found: char,
+    },
+    /// One of the characters after the first is invalid.
+    InvalidRestChar {
+        /// The invalid character.
+        found: char,
+        /// The byte index of the character in the value.
+        index: usize,
+    },
+}
+
+impl error::Error for NcNameError {
+
+    fn description(&self) -> &str { "NcName error" }
+}
+
+impl fmt::Display for NcNameError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NcNameError::Empty(ref error) =>
+                fmt::Display::fmt(error, fmt),
+            NcNameError::InvalidStartChar { found } =>
+                write!(fmt, "value begins with invalid character `{}`", found.escape_default()),
+            NcNameError::InvalidRestChar { found, index } =>
+                write!(
+                    fmt,
+                    "value contains invalid character `{}` at index {}",
+                    found.escape_default(),
+                    index,
+                ),
+        }
+    }
+}
+
+impl ::CheckErrorCode for NcNameError {
+
+    fn code(&self) -> &'static str {
+        match *self {
+            NcNameError::Empty(ref error) => error.code(),
+            NcNameError::InvalidStartChar { .. } => "nc_name_start_char",
+            NcNameError::InvalidRestChar { .. } => "nc_name_char",
+        }
+    }
+}
+
+/// Ensure a value is a valid XML NCName, a name without a namespace colon.
+///
+/// To be a valid NCName, a value has to be not empty and only contain the following
+/// characters:
+///
+/// * `A` to `Z` (uppercase ASCII alphabetic characters)
+/// * `a` to `z` (lowercase ASCII alphabetic characters)
+/// * `_` (underscore)
+/// * `0` to `9` (ASCII digits, **not allowed at the beginning**)
+/// * `.` and `-` (period and hyphen, **not allowed at the beginning**)
+///
+/// This is the ASCII subset of the XML rules. Names using the non-ASCII letters XML permits
+/// are rejected.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// assert!(textkind::check::NcName::check("item").is_ok());
+/// assert!(textkind::check::NcName::check("_item-2.old").is_ok());
+///
+/// assert!(textkind::check::NcName::check("xs:item").is_err());
+/// assert!(textkind::check::NcName::check("-item").is_err());
+/// assert!(textkind::check::NcName::check("").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NcName {
+    _unconstructable: ::Void,
+}
+
+impl ::Check for NcName {
+
+    type Error = NcNameError;
+
+    fn check(value: &str) -> Result<(), Self::Error> {
+        NotEmpty::check(value).map_err(NcNameError::Empty)?;
+        let mut chars = value.char_indices();
+        let (_, start_char) = chars.next().expect("non-empty value has at least one char");
+        match start_char {
+            'a'..='z' | 'A'..='Z' | '_' => (),
+            _ => return Err(NcNameError::InvalidStartChar { found: start_char }),
+        }
+        for (index, rest_char) in chars {
+            match rest_char {
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '.' | '-' => (),
+                _ => return Err(NcNameError::InvalidRestChar { found: rest_char, index }),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ::BoundedBytes for NcName {
+
+    const MAX: Option<usize> = None;
+}
+
+/// Signals that a value is invalid because it matches none of a set of patterns.
+#[cfg(feature = "regex")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegexSetError {
+    /// The patterns the value was matched against.
+    pub patterns: &'static [&'static str],
+}
+
+#[cfg(feature = "regex")]
+impl error::Error for RegexSetError {
+
+    fn description(&self) -> &str { "RegexSet error" }
+}
+
+#[cfg(feature = "regex")]
+impl fmt::Display for RegexSetError {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "value does not match any of the patterns")?;
+        for (index, pattern) in self.patterns.iter().enumerate() {
+            let separator = if index == 0 { " " } else { ", " };
+            write!(fmt, "{}`{}`", separator, pattern)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "regex")]
+impl ::CheckErrorCode for RegexSetError {
+
+    fn code(&self) -> &'static str { "regex_set" }
+}
+
+/// A set of regular expressions compiled on first use.
+///
+/// This is used by the checks generated with `regex_any!` and can be stored in a `static`.
+///
+/// This requires the `regex` feature.
+///
+/// # Panics
+///
+/// Matching panics if one of the patterns is not a valid regular expression.
+#[cfg(feature = "regex")]
+pub struct LazyRegexSet {
+    patterns: &'static [&'static str],
+    set: ::std::sync::OnceLock<::regex::RegexSet>,
+}
+
+#[cfg(feature = "regex")]
+impl LazyRegexSet {
+
+    /// Create a set from the given patterns without compiling them.
+    pub const fn new(patterns: &'static [&'static str]) -> LazyRegexSet {
+        LazyRegexSet {
+            patterns,
+            set: ::std::sync::OnceLock::new(),
+        }
+    }
+
+    /// Get the patterns in the set.
+    pub fn patterns(&self) -> &'static [&'static str] { self.patterns }
+
+    /// Check that a value matches at least one of the patterns.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RegexSetError` if no pattern matches the value.
+    pub fn check(&self, value: &str) -> Result<(), RegexSetError> {
+        let patterns = self.patterns;
+        let set = self.set.get_or_init(|| {
+            ::regex::RegexSet::new(patterns).expect("valid regular expressions")
+        });
+        if set.is_match(value) {
+            Ok(())
+        } else {
+            Err(RegexSetError { patterns })
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl fmt::Debug for LazyRegexSet {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "LazyRegexSet({:?})", self.patterns)
+    }
+}
+
+/// Define a check requiring a value to match at least one of several regular expressions.
+///
+/// The patterns are compiled into a single `regex::RegexSet` the first time the check runs,
+/// which is faster than combining multiple pattern checks with `Or`. Patterns are not
+/// anchored implicitly, use `^` and `$` to match the whole value.
+///
+/// This requires the `regex` feature.
+///
+/// # Panics
+///
+/// The generated check panics if one of the patterns is not a valid regular expression.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Check;
+///
+/// regex_any!(
+///     /// Ensure a value is a hex or binary literal.
+///     Literal, r"^0x[0-9a-f]+$", r"^0b[01]+$"
+/// );
+///
+/// assert!(Literal::check("0xff").is_ok());
+/// assert!(Literal::check("0b101").is_ok());
+/// assert!(Literal::check("255").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "regex")]
+#[macro_export]
+macro_rules! regex_any {
+    ($(#[$meta:meta])* $name:ident, $($pattern:expr),+ $(,)*) => {
+        $(#[$meta])*
+        #[allow(missing_debug_implementations)]
+        pub enum $name {}
+
+        impl $crate::Check for $name {
+
+            type Error = $crate::check::RegexSetError;
+
+            fn check(value: &str) -> Result<(), Self::Error> {
+                static SET: $crate::check::LazyRegexSet =
+                    $crate::check::LazyRegexSet::new(&[$($pattern),+]);
+                SET.check(value)
+            }
+        }
+
+        impl $crate::BoundedBytes for $name {
+
+            const MAX: Option<usize> = None;
+        }
+    }
+}
 
+#[cfg(feature = "regex")]
+pub use regex_any;
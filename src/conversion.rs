@@ -77,6 +77,111 @@ where
 /// * `E` is the error.
 pub struct ConvertError<K, D, E>(pub E, pub ::Text<K, D>);
 
+impl<K, D, E> ConvertError<K, D, E>
+where
+    K: ::Kind,
+    D: ::Dynamic,
+{
+    /// Extract the error and the source text into a tuple.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text = textkind::Title::<String>::try_from_str("foo")?;
+    /// let convert_error = textkind::ConvertError("custom error", text);
+    ///
+    /// let (error, text) = convert_error.split();
+    /// assert_eq!(error, "custom error");
+    /// assert_eq!(text.as_str(), "foo");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn split(self) -> (E, ::Text<K, D>) { (self.0, self.1) }
+
+    /// Access the source text associated with the error.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text = textkind::Title::<String>::try_from_str("foo")?;
+    /// let convert_error = textkind::ConvertError("custom error", text);
+    ///
+    /// assert_eq!(convert_error.value().as_str(), "foo");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn value(&self) -> &::Text<K, D> { &self.1 }
+
+    /// Map the error to another type while keeping the source text.
+    ///
+    /// Mapping a custom error to an `Error<TargetKind>` allows using the `From` conversion
+    /// into `ErrorWithValue<TargetKind, Text<SourceKind, D>>`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text = textkind::Title::<String>::try_from_str("foo")?;
+    /// let convert_error = textkind::ConvertError("custom error", text);
+    ///
+    /// let convert_error = convert_error.map_error(|error| error.len());
+    /// assert_eq!(convert_error.0, 12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn map_error<E2, F>(self, map: F) -> ConvertError<K, D, E2>
+    where
+        F: FnOnce(E) -> E2,
+    {
+        ConvertError(map(self.0), self.1)
+    }
+
+    /// Box the error as a thread-safe error trait object.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    /// use std::error::Error;
+    /// use std::sync::Arc;
+    ///
+    /// let text = textkind::Title::<Arc<String>>::try_from_str("foo")?;
+    /// let convert_error = textkind::ConvertError(textkind::check::NotEmptyError, text);
+    ///
+    /// let error: Box<Error + Send + Sync> = convert_error.boxed();
+    /// assert_eq!(error.to_string(), "value is empty");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn boxed(self) -> Box<dyn error::Error + Send + Sync>
+    where
+        Self: error::Error + Send + Sync + 'static,
+    {
+        Box::new(self)
+    }
+}
+
 impl<K, D, E> error::Error for ConvertError<K, D, E>
 where
     K: ::Kind,
@@ -19,6 +19,17 @@ pub enum Data<T> {
     Small(small::SmallString),
 }
 
+/// Storage used by a `Data<T>` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageKind {
+    /// The value is a static str.
+    Static,
+    /// The value is held by the dynamic storage.
+    Dynamic,
+    /// The value is held by the inline small string buffer.
+    Small,
+}
+
 impl<T> Data<T>
 where
     T: ::Dynamic
@@ -36,11 +47,29 @@ where
         }
     }
 
+    /// Create a small or dynamic data value from a string slice with an inline size limit.
+    ///
+    /// Values are only stored inline when they are at most `threshold` bytes long. The
+    /// inline buffer has a fixed size, so thresholds above it behave like `from_str`.
+    pub fn from_str_with_threshold(value: &str, threshold: usize) -> Data<T> {
+        if value.len() <= threshold {
+            if let Some(small) = small::SmallString::try_from(value) {
+                return Data::Small(small);
+            }
+        }
+        Data::Dynamic(T::from_str(value))
+    }
+
     /// Create a dynamic data value from a string.
     pub fn from_string(value: String) -> Data<T> {
         Data::Dynamic(T::from_string(value))
     }
 
+    /// Create a dynamic data value from a boxed string slice.
+    pub fn from_boxed_str(value: Box<str>) -> Data<T> {
+        Data::Dynamic(T::from_boxed_str(value))
+    }
+
     /// Wrap an existing dynamic data storage.
     pub fn from_dynamic(value: T) -> Data<T> {
         Data::Dynamic(value)
@@ -71,7 +100,42 @@ where
         }
     }
 
+    /// Replace the contents of `buf` with the stored text, reusing its capacity.
+    pub fn clone_into_string_buf(&self, buf: &mut String) {
+        buf.clear();
+        buf.push_str(self.as_str());
+    }
+
+    /// Transform the stored text while preserving the storage where possible.
+    ///
+    /// When `f` returns a `Modified::Sub` subslice of the given value, static data is narrowed
+    /// to the subslice, small data stays small, and dynamic data is kept as it is when the
+    /// subslice covers the whole value. Other subslices are stored as new small or dynamic
+    /// values. A `Modified::New` result becomes dynamic data.
+    pub fn map_str<F>(self, f: F) -> Data<T>
+    where
+        F: for<'a> FnOnce(&'a str) -> ::Modified<'a, T>,
+    {
+        let (start, end) = {
+            let value = self.as_str();
+            match f(value) {
+                ::Modified::New(dynamic) => return Data::Dynamic(dynamic),
+                ::Modified::Sub(sub) => {
+                    let start = (sub.as_ptr() as usize).wrapping_sub(value.as_ptr() as usize);
+                    if start > value.len() || sub.len() > value.len() - start {
+                        return Data::from_str(sub);
+                    }
+                    (start, start + sub.len())
+                },
+            }
+        };
+        ::sub_data(self, start, end)
+    }
+
     /// Convert to another dynamic storage.
+    ///
+    /// Dynamic values are converted with `Dynamic::from`. For the provided storages this moves
+    /// the `String` when it can be extracted instead of copying its contents.
     pub fn convert<U>(self) -> Data<U>
     where
         U: ::Dynamic,
@@ -83,6 +147,16 @@ where
         }
     }
 
+    /// Release spare capacity of dynamic data via `Dynamic::shrink`.
+    ///
+    /// Static and small data values are returned as they are.
+    pub fn shrink(self) -> Data<T> {
+        match self {
+            Data::Dynamic(dynamic) => Data::Dynamic(dynamic.shrink()),
+            other => other,
+        }
+    }
+
     /// Turn the data value into a string, possibly extracting it without reallocating.
     pub fn into_string(self) -> String {
         match self {
@@ -110,6 +184,23 @@ where
         }
     }
 
+    /// Borrow the dynamic storage value, if there is one.
+    pub fn as_dynamic(&self) -> Option<&T> {
+        match *self {
+            Data::Dynamic(ref dynamic) => Some(dynamic),
+            _ => None,
+        }
+    }
+
+    /// Get the kind of storage holding the data.
+    pub fn storage_kind(&self) -> StorageKind {
+        match *self {
+            Data::Static(_) => StorageKind::Static,
+            Data::Dynamic(_) => StorageKind::Dynamic,
+            Data::Small(_) => StorageKind::Small,
+        }
+    }
+
     /// Check if data is a static str value.
     pub fn is_static(&self) -> bool {
         if let Data::Static(_) = *self {
@@ -149,8 +240,109 @@ mod tests {
         assert!(Data::<String>::from_str(&"X".repeat(17)).is_dynamic());
     }
 
+    #[test]
+    fn clone_into_string_buf() {
+        let mut buf = String::with_capacity(64);
+        let ptr = buf.as_ptr();
+        Data::<String>::from_static_str("foo").clone_into_string_buf(&mut buf);
+        assert_eq!(buf, "foo");
+        Data::<String>::from_str("ba").clone_into_string_buf(&mut buf);
+        assert_eq!(buf, "ba");
+        assert_eq!(buf.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn map_str() {
+        use Modified;
+
+        let data = Data::<String>::from_static_str("foo bar").map_str(|v| Modified::Sub(&v[4..]));
+        assert!(data.is_static());
+        assert_eq!(data.as_str(), "bar");
+
+        let data = Data::<String>::from_static_str("foo").map_str(|v| Modified::New(v.repeat(2)));
+        assert!(data.is_dynamic());
+        assert_eq!(data.as_str(), "foofoo");
+
+        let data = Data::<String>::from_str("foo bar").map_str(|v| Modified::Sub(&v[..3]));
+        assert!(data.is_small());
+        assert_eq!(data.as_str(), "foo");
+
+        let data = Data::<String>::from_str("foo").map_str(|v| Modified::New(v.to_uppercase()));
+        assert!(data.is_dynamic());
+        assert_eq!(data.as_str(), "FOO");
+
+        let long = "X".repeat(32);
+        let data = Data::<String>::from_string(long.clone());
+        let pointer = data.as_str().as_ptr();
+        let data = data.map_str(|v| Modified::Sub(v.trim()));
+        assert!(data.is_dynamic());
+        assert_eq!(data.as_str().as_ptr(), pointer);
+
+        let data = Data::<String>::from_string(long.clone()).map_str(|v| Modified::Sub(&v[..4]));
+        assert!(data.is_small());
+        assert_eq!(data.as_str(), "XXXX");
+
+        let data = Data::<String>::from_string(long.clone()).map_str(|v| Modified::Sub(&v[1..]));
+        assert!(data.is_dynamic());
+        assert_eq!(data.as_str(), &long[1..]);
+
+        let data = Data::<String>::from_string(long).map_str(|_| Modified::Sub("other"));
+        assert!(data.is_small());
+        assert_eq!(data.as_str(), "other");
+    }
+
+    #[test]
+    fn threshold() {
+        assert!(Data::<String>::from_str_with_threshold("foo", 3).is_small());
+        assert!(Data::<String>::from_str_with_threshold("foo", 2).is_dynamic());
+        assert!(Data::<String>::from_str_with_threshold("", 0).is_small());
+        assert!(Data::<String>::from_str_with_threshold(&"X".repeat(16), 64).is_small());
+        assert!(Data::<String>::from_str_with_threshold(&"X".repeat(17), 64).is_dynamic());
+    }
+
+    #[test]
+    fn storage_kind() {
+        assert_eq!(Data::<String>::from_static_str("foo").storage_kind(), StorageKind::Static);
+        assert_eq!(Data::<String>::from_str("foo").storage_kind(), StorageKind::Small);
+        assert_eq!(Data::<String>::from_string("foo".into()).storage_kind(), StorageKind::Dynamic);
+    }
+
+    #[test]
+    fn as_dynamic() {
+        assert_eq!(Data::<String>::from_string("foo".into()).as_dynamic(), Some(&"foo".to_string()));
+        assert_eq!(Data::<String>::from_static_str("foo").as_dynamic(), None);
+        assert_eq!(Data::<String>::from_str("foo").as_dynamic(), None);
+    }
+
     #[test]
     fn static_construction() {
         assert!(Data::<String>::from_static_str("foo").is_static());
     }
+
+    #[test]
+    fn boxed_str_construction() {
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        let boxed: Box<str> = "foo".into();
+        let pointer = boxed.as_ptr();
+        let data = Data::<String>::from_boxed_str(boxed);
+        assert!(data.is_dynamic());
+        assert_eq!(data.as_str().as_ptr(), pointer);
+
+        let boxed: Box<str> = "foo".into();
+        let pointer = boxed.as_ptr();
+        let data = Data::<Box<str>>::from_boxed_str(boxed);
+        assert_eq!(data.as_str().as_ptr(), pointer);
+
+        let boxed: Box<str> = "foo".into();
+        let pointer = boxed.as_ptr();
+        let data = Data::<Rc<String>>::from_boxed_str(boxed);
+        assert_eq!(data.as_str().as_ptr(), pointer);
+
+        let boxed: Box<str> = "foo".into();
+        let pointer = boxed.as_ptr();
+        let data = Data::<Arc<String>>::from_boxed_str(boxed);
+        assert_eq!(data.as_str().as_ptr(), pointer);
+    }
 }
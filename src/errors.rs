@@ -1,6 +1,7 @@
 
 use std::error;
 use std::fmt;
+use std::str;
 
 /// An error with an associated value.
 ///
@@ -106,6 +107,31 @@ where
     {
         ErrorWithValue(self.0, map(self.1))
     }
+
+    /// Box the error as a thread-safe error trait object.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    /// use std::error::Error;
+    ///
+    /// let error: Box<Error + Send + Sync> =
+    ///     textkind::Title::<String>::try_from_string("invalid\ntitle".to_string())
+    ///     .err()
+    ///     .expect("input with control characters is not a valid title")
+    ///     .boxed();
+    ///
+    /// assert_eq!(error.to_string(), "invalid title");
+    /// ```
+    pub fn boxed(self) -> Box<dyn error::Error + Send + Sync>
+    where
+        Self: error::Error + Send + Sync + 'static,
+    {
+        Box::new(self)
+    }
 }
 
 impl<K, V> Clone for ErrorWithValue<K, V>
@@ -148,6 +174,14 @@ where
     }
 }
 
+impl<K, V> ::CheckErrorCode for ErrorWithValue<K, V>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: ::CheckErrorCode,
+{
+    fn code(&self) -> &'static str { self.0.code() }
+}
+
 impl<K, V> Eq for ErrorWithValue<K, V>
 where
     K: ::Kind,
@@ -203,6 +237,91 @@ where
     pub fn with_value<V>(self, value: V) -> ErrorWithValue<K, V> {
         ErrorWithValue(self.0, value)
     }
+
+    /// Describe the error in a sentence combining the kind description and the check error.
+    ///
+    /// This uses the `Display` implementation of the check error for the specific reason.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    ///
+    /// let error = textkind::Title::<String>::try_from_str("invalid\ntitle")
+    ///     .err()
+    ///     .expect("input with control characters is not a valid title");
+    ///
+    /// assert_eq!(
+    ///     error.explain(),
+    ///     "invalid title: value contains 1 control character(s)",
+    /// );
+    /// ```
+    pub fn explain(&self) -> String
+    where
+        <<K as ::Kind>::Check as ::Check>::Error: fmt::Display,
+    {
+        format!("{}: {}", self, self.0)
+    }
+
+    /// Get a display adapter describing the error together with the checked value.
+    ///
+    /// This renders like [`explain`](#method.explain) with the value inserted, for callers
+    /// that still hold the value passed to a constructor like `Text::try_from_str`. The value
+    /// is escaped and cut off with `…` after 64 characters.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    ///
+    /// let input = "invalid\ntitle";
+    /// let error = textkind::Title::<String>::try_from_str(input)
+    ///     .err()
+    ///     .expect("input with control characters is not a valid title");
+    ///
+    /// assert_eq!(
+    ///     error.display_with_value(input).to_string(),
+    ///     "invalid title: `invalid\\ntitle` (value contains 1 control character(s))",
+    /// );
+    /// ```
+    pub fn display_with_value<'a>(&'a self, value: &'a str) -> impl fmt::Display + 'a
+    where
+        <<K as ::Kind>::Check as ::Check>::Error: fmt::Display,
+    {
+        WithValue {
+            error: self,
+            value,
+        }
+    }
+
+    /// Box the error as a thread-safe error trait object.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    /// use std::error::Error;
+    ///
+    /// let error: Box<Error + Send + Sync> =
+    ///     textkind::Title::<String>::try_from_str("invalid\ntitle")
+    ///     .err()
+    ///     .expect("input with control characters is not a valid title")
+    ///     .boxed();
+    ///
+    /// assert_eq!(error.to_string(), "invalid title");
+    /// ```
+    pub fn boxed(self) -> Box<dyn error::Error + Send + Sync>
+    where
+        Self: error::Error + Send + Sync + 'static,
+    {
+        Box::new(self)
+    }
 }
 
 impl<K> Clone for Error<K>
@@ -242,6 +361,14 @@ where
     }
 }
 
+impl<K> ::CheckErrorCode for Error<K>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: ::CheckErrorCode,
+{
+    fn code(&self) -> &'static str { self.0.code() }
+}
+
 impl<K> Eq for Error<K>
 where
     K: ::Kind,
@@ -258,3 +385,327 @@ where
     }
 }
 
+
+/// An error returned when slicing a text into another kind failed.
+///
+/// The range can be invalid, or the sliced value can be invalid for the target kind.
+pub enum SliceError<K>
+where
+    K: ::Kind,
+{
+    /// The range is out of bounds or doesn't fall on `char` boundaries.
+    InvalidRange {
+        /// The start of the requested range.
+        start: usize,
+        /// The end of the requested range.
+        end: usize,
+    },
+    /// The sliced value is not valid for the target kind.
+    Invalid(Error<K>),
+}
+
+impl<K> Clone for SliceError<K>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            SliceError::InvalidRange { start, end } => SliceError::InvalidRange { start, end },
+            SliceError::Invalid(ref error) => SliceError::Invalid(error.clone()),
+        }
+    }
+}
+
+impl<K> fmt::Debug for SliceError<K>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SliceError::InvalidRange { start, end } =>
+                write!(fmt, "InvalidRange {{ start: {:?}, end: {:?} }}", start, end),
+            SliceError::Invalid(ref error) =>
+                write!(fmt, "Invalid({:?})", error),
+        }
+    }
+}
+
+impl<K> error::Error for SliceError<K>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: error::Error,
+{
+    fn description(&self) -> &str { "text slice error" }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
+        match *self {
+            SliceError::InvalidRange { .. } => None,
+            SliceError::Invalid(ref error) => Some(error),
+        }
+    }
+}
+
+impl<K> fmt::Display for SliceError<K>
+where
+    K: ::Kind,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SliceError::InvalidRange { start, end } =>
+                write!(fmt, "invalid range {}..{}", start, end),
+            SliceError::Invalid(ref error) =>
+                fmt::Display::fmt(error, fmt),
+        }
+    }
+}
+
+impl<K> Eq for SliceError<K>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: Eq,
+{ }
+
+impl<K> PartialEq for SliceError<K>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                SliceError::InvalidRange { start, end },
+                SliceError::InvalidRange { start: other_start, end: other_end },
+            ) => start == other_start && end == other_end,
+            (SliceError::Invalid(error), SliceError::Invalid(other)) => error == other,
+            _ => false,
+        }
+    }
+}
+
+/// An error returned when constructing a text from bytes failed.
+///
+/// The bytes can be invalid UTF-8, or the decoded value can be invalid for the kind.
+pub enum TextFromBytesError<K>
+where
+    K: ::Kind,
+{
+    /// The bytes are not valid UTF-8.
+    Utf8(str::Utf8Error),
+    /// The decoded value is not valid for the kind.
+    Check(Error<K>),
+}
+
+impl<K> Clone for TextFromBytesError<K>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            TextFromBytesError::Utf8(error) => TextFromBytesError::Utf8(error),
+            TextFromBytesError::Check(ref error) => TextFromBytesError::Check(error.clone()),
+        }
+    }
+}
+
+impl<K> fmt::Debug for TextFromBytesError<K>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TextFromBytesError::Utf8(ref error) => write!(fmt, "Utf8({:?})", error),
+            TextFromBytesError::Check(ref error) => write!(fmt, "Check({:?})", error),
+        }
+    }
+}
+
+impl<K> error::Error for TextFromBytesError<K>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: error::Error,
+{
+    fn description(&self) -> &str { "text from bytes error" }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
+        match *self {
+            TextFromBytesError::Utf8(ref error) => Some(error),
+            TextFromBytesError::Check(ref error) => Some(error),
+        }
+    }
+}
+
+impl<K> fmt::Display for TextFromBytesError<K>
+where
+    K: ::Kind,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TextFromBytesError::Utf8(ref error) =>
+                write!(fmt, "invalid {}: {}", K::DESCRIPTION, error),
+            TextFromBytesError::Check(ref error) =>
+                fmt::Display::fmt(error, fmt),
+        }
+    }
+}
+
+impl<K> Eq for TextFromBytesError<K>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: Eq,
+{ }
+
+impl<K> PartialEq for TextFromBytesError<K>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TextFromBytesError::Utf8(error), TextFromBytesError::Utf8(other)) => error == other,
+            (TextFromBytesError::Check(error), TextFromBytesError::Check(other)) => error == other,
+            _ => false,
+        }
+    }
+}
+
+/// An error returned when one side of a split text is invalid.
+pub enum SplitError<K1, K2>
+where
+    K1: ::Kind,
+    K2: ::Kind,
+{
+    /// The part before the delimiter is invalid.
+    Left(Error<K1>),
+    /// The part after the delimiter is invalid.
+    Right(Error<K2>),
+}
+
+impl<K1, K2> Clone for SplitError<K1, K2>
+where
+    K1: ::Kind,
+    K2: ::Kind,
+    <<K1 as ::Kind>::Check as ::Check>::Error: Clone,
+    <<K2 as ::Kind>::Check as ::Check>::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            SplitError::Left(ref error) => SplitError::Left(error.clone()),
+            SplitError::Right(ref error) => SplitError::Right(error.clone()),
+        }
+    }
+}
+
+impl<K1, K2> fmt::Debug for SplitError<K1, K2>
+where
+    K1: ::Kind,
+    K2: ::Kind,
+    <<K1 as ::Kind>::Check as ::Check>::Error: fmt::Debug,
+    <<K2 as ::Kind>::Check as ::Check>::Error: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SplitError::Left(ref error) => write!(fmt, "Left({:?})", error),
+            SplitError::Right(ref error) => write!(fmt, "Right({:?})", error),
+        }
+    }
+}
+
+impl<K1, K2> error::Error for SplitError<K1, K2>
+where
+    K1: ::Kind,
+    K2: ::Kind,
+    <<K1 as ::Kind>::Check as ::Check>::Error: error::Error,
+    <<K2 as ::Kind>::Check as ::Check>::Error: error::Error,
+{
+    fn description(&self) -> &str { "text split error" }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
+        match *self {
+            SplitError::Left(ref error) => Some(error),
+            SplitError::Right(ref error) => Some(error),
+        }
+    }
+}
+
+impl<K1, K2> fmt::Display for SplitError<K1, K2>
+where
+    K1: ::Kind,
+    K2: ::Kind,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SplitError::Left(ref error) => write!(fmt, "left part: {}", error),
+            SplitError::Right(ref error) => write!(fmt, "right part: {}", error),
+        }
+    }
+}
+
+impl<K1, K2> Eq for SplitError<K1, K2>
+where
+    K1: ::Kind,
+    K2: ::Kind,
+    <<K1 as ::Kind>::Check as ::Check>::Error: Eq,
+    <<K2 as ::Kind>::Check as ::Check>::Error: Eq,
+{ }
+
+impl<K1, K2> PartialEq for SplitError<K1, K2>
+where
+    K1: ::Kind,
+    K2: ::Kind,
+    <<K1 as ::Kind>::Check as ::Check>::Error: PartialEq,
+    <<K2 as ::Kind>::Check as ::Check>::Error: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (SplitError::Left(error), SplitError::Left(other)) => error == other,
+            (SplitError::Right(error), SplitError::Right(other)) => error == other,
+            _ => false,
+        }
+    }
+}
+
+/// The result of splitting a text into texts of two kinds.
+///
+/// The type parameters are as follows:
+///
+/// * `K1` is the kind of the part before the delimiter.
+/// * `K2` is the kind of the part after the delimiter.
+/// * `D` is the dynamic storage.
+pub type SplitResult<K1, K2, D> = Result<
+    (::Text<K1, D>, ::Text<K2, D>),
+    SplitError<K1, K2>,
+>;
+
+// Maximum number of value characters written by `Error::display_with_value`.
+const DISPLAY_VALUE_MAX_CHARS: usize = 64;
+
+// Display adapter used by `Error::display_with_value`.
+struct WithValue<'a, K>
+where
+    K: ::Kind + 'a,
+{
+    error: &'a Error<K>,
+    value: &'a str,
+}
+
+impl<'a, K> fmt::Display for WithValue<'a, K>
+where
+    K: ::Kind,
+    <<K as ::Kind>::Check as ::Check>::Error: fmt::Display,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}: `", self.error)?;
+        let mut chars = self.value.chars();
+        for c in chars.by_ref().take(DISPLAY_VALUE_MAX_CHARS) {
+            write!(fmt, "{}", c.escape_debug())?;
+        }
+        if chars.next().is_some() {
+            fmt.write_str("\u{2026}")?;
+        }
+        write!(fmt, "` ({})", self.error.0)
+    }
+}
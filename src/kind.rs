@@ -57,3 +57,97 @@ impl ::Kind for IdentifierLax {
     const DESCRIPTION: &'static str = "identifier";
 }
 
+/// Text kind representing a semantic version.
+///
+/// This kind combines the predefined `Semver` check with the `MaxBytes256` check.
+#[allow(missing_debug_implementations)]
+pub struct Semver {
+    _unconstructable: ::Void,
+}
+
+impl ::Kind for Semver {
+
+    type Check = check::And<check::MaxBytes256, check::Semver>;
+
+    const DESCRIPTION: &'static str = "semantic version";
+}
+
+/// Text kind representing a version, the same as `Semver`.
+pub type Version = Semver;
+
+/// Text kind representing an environment variable name.
+///
+/// This kind combines the predefined `EnvVarName` check with the `MaxBytes256` check.
+#[allow(missing_debug_implementations)]
+pub struct EnvVarName {
+    _unconstructable: ::Void,
+}
+
+impl ::Kind for EnvVarName {
+
+    type Check = check::And<check::MaxBytes256, check::EnvVarName>;
+
+    const DESCRIPTION: &'static str = "environment variable name";
+}
+
+/// Text kind representing a tag.
+///
+/// This kind combines the predefined `Tag` check with the `MaxBytes64` check.
+#[allow(missing_debug_implementations)]
+pub struct Tag {
+    _unconstructable: ::Void,
+}
+
+impl ::Kind for Tag {
+
+    type Check = check::And<check::MaxBytes64, check::Tag>;
+
+    const DESCRIPTION: &'static str = "tag";
+}
+
+/// Text kind representing non-empty ASCII text.
+///
+/// This kind combines the predefined `NotEmpty` and `Ascii` checks with the `MaxBytes512`
+/// check. Empty values are rejected like for `Title`. Use `check::Ascii` in a custom kind to
+/// allow them.
+#[allow(missing_debug_implementations)]
+pub struct AsciiText {
+    _unconstructable: ::Void,
+}
+
+impl ::Kind for AsciiText {
+
+    type Check = check::And<check::MaxBytes512, check::And<check::NotEmpty, check::Ascii>>;
+
+    const DESCRIPTION: &'static str = "ASCII text";
+}
+
+/// Text kind representing a time zone name.
+///
+/// This kind combines the predefined `TzName` check with the `MaxBytes64` check.
+#[allow(missing_debug_implementations)]
+pub struct TzName {
+    _unconstructable: ::Void,
+}
+
+impl ::Kind for TzName {
+
+    type Check = check::And<check::MaxBytes64, check::TzName>;
+
+    const DESCRIPTION: &'static str = "time zone name";
+}
+
+/// Text kind representing an XML NCName.
+///
+/// This kind combines the predefined `NcName` check with the `MaxBytes256` check.
+#[allow(missing_debug_implementations)]
+pub struct NcName {
+    _unconstructable: ::Void,
+}
+
+impl ::Kind for NcName {
+
+    type Check = check::And<check::MaxBytes256, check::NcName>;
+
+    const DESCRIPTION: &'static str = "XML name";
+}
@@ -15,10 +15,13 @@
 //! * Value validation associated with text kinds.
 //! * Special storage for `&'static str` values avoiding allocation.
 //! * Can sometimes avoid allocation for small strings.
-//! * Parameterised dynamic storage (`String`, `Rc<String>` or `Arc<String>`).
+//! * Parameterised dynamic storage (`String`, `Box<str>`, `Rc<String>` or
+//!   `Arc<String>`).
 //! * Checked conversions between kinds.
 //! * Transition from one dynamic storage to another.
 //! * Optional [serde](https://crates.io/crates/serde) integration.
+//! * Optional [rkyv](https://crates.io/crates/rkyv) integration.
+//! * Optional [borsh](https://crates.io/crates/borsh) integration.
 //!
 //! The code is not performance-oriented and kept rather simple. The dynamic storage parameter
 //! merely allows avoiding unnecessary copies. The API is also focused on text values that
@@ -27,6 +30,13 @@
 //! # Features
 //!
 //! * `serde` adds [serde](https://crates.io/crates/serde) serialization and deserialization.
+//!   The `serde_support` module provides functions for serde's `with` field attribute.
+//! * `rkyv` adds [rkyv](https://crates.io/crates/rkyv) archiving. See `rkyv_support` for the
+//!   validation policy.
+//! * `borsh` adds [borsh](https://crates.io/crates/borsh) serialization and deserialization.
+//! * `unicode-normalization` adds normalized comparisons via
+//!   [unicode-normalization](https://crates.io/crates/unicode-normalization).
+//! * `regex` adds pattern based checks via [regex](https://crates.io/crates/regex).
 //!
 //! # Examples
 //!
@@ -119,6 +129,18 @@
 #[cfg(feature = "serde")]
 extern crate serde;
 
+#[cfg(feature = "rkyv")]
+extern crate rkyv;
+
+#[cfg(feature = "borsh")]
+extern crate borsh;
+
+#[cfg(feature = "unicode-normalization")]
+extern crate unicode_normalization;
+
+#[cfg(feature = "regex")]
+extern crate regex;
+
 use std::borrow;
 use std::cmp;
 use std::fmt;
@@ -146,7 +168,13 @@ mod traits;
 pub use traits::*;
 
 #[cfg(feature = "serde")]
-mod serde_support;
+pub mod serde_support;
+
+#[cfg(feature = "rkyv")]
+pub mod rkyv_support;
+
+#[cfg(feature = "borsh")]
+mod borsh_support;
 
 /// Predefined title text type.
 ///
@@ -165,9 +193,88 @@ pub type Identifier<D> = Text<kind::Identifier, D>;
 /// parameter.
 pub type IdentifierLax<D> = Text<kind::IdentifierLax, D>;
 
+/// Predefined semantic version text type.
+///
+/// This uses `kind::Semver` as a text kind while keeping the dynamic storage as a type
+/// parameter.
+pub type Semver<D> = Text<kind::Semver, D>;
+
+/// Predefined version text type.
+///
+/// This is the same type as `Semver<D>`, using the `kind::Version` alias of `kind::Semver`.
+pub type Version<D> = Text<kind::Version, D>;
+
+/// Predefined environment variable name text type.
+///
+/// This uses `kind::EnvVarName` as a text kind while keeping the dynamic storage as a type
+/// parameter.
+pub type EnvVarName<D> = Text<kind::EnvVarName, D>;
+
+/// Predefined tag text type.
+///
+/// This uses `kind::Tag` as a text kind while keeping the dynamic storage as a type parameter.
+pub type Tag<D> = Text<kind::Tag, D>;
+
+/// Predefined ASCII text type.
+///
+/// This uses `kind::AsciiText` as a text kind while keeping the dynamic storage as a type
+/// parameter.
+pub type AsciiText<D> = Text<kind::AsciiText, D>;
+
+/// Predefined time zone name text type.
+///
+/// This uses `kind::TzName` as a text kind while keeping the dynamic storage as a type
+/// parameter.
+pub type TzName<D> = Text<kind::TzName, D>;
+
+/// Predefined XML NCName text type.
+///
+/// This uses `kind::NcName` as a text kind while keeping the dynamic storage as a type
+/// parameter.
+pub type NcName<D> = Text<kind::NcName, D>;
+
 // Used to make kind and check types unconstructable.
 enum Void {}
 
+/// Format a value and validate it as a text.
+///
+/// This formats the arguments like `format!` and constructs the given text type from the
+/// result with `Text::try_from_args`. The text type, including the dynamic storage, is given
+/// before a `;`, followed by the format string and its arguments.
+///
+/// # Errors
+///
+/// Evaluates to an `Err(ErrorWithValue<K, String>)` with the formatted `String` when the value
+/// is invalid.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::Title;
+///
+/// let text = text_format!(Title<String>; "{}-{}", "foo", 23)?;
+/// assert_eq!(text.as_str(), "foo-23");
+///
+/// let error = text_format!(Title<String>; "{}\n{}", "foo", 23)
+///     .err()
+///     .expect("titles cannot contain control characters");
+/// assert_eq!(error.value(), "foo\n23");
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! text_format {
+    ($text:ty; $($args:tt)+) => {
+        <$text>::try_from_args(format_args!($($args)+))
+    }
+}
+
 macro_rules! error_with_value {
     ($value:ident, $result:expr) => {{
         match $result {
@@ -177,6 +284,31 @@ macro_rules! error_with_value {
     }}
 }
 
+// Stores a newly built string inline if it is small enough.
+fn owned_data<D>(value: String) -> Data<D>
+where
+    D: Dynamic,
+{
+    match SmallString::try_from(&value) {
+        Some(small) => Data::Small(small),
+        None => Data::from_string(value),
+    }
+}
+
+// Narrows stored data to a subslice, keeping static references static.
+fn sub_data<D>(data: Data<D>, start: usize, end: usize) -> Data<D>
+where
+    D: Dynamic,
+{
+    if start == 0 && end == data.as_str().len() {
+        return data;
+    }
+    match data {
+        Data::Static(value) => Data::Static(&value[start..end]),
+        other => Data::from_str(&other.as_str()[start..end]),
+    }
+}
+
 /// Encapsulates a modification result.
 ///
 /// This is used to indicate if a modified value is a new value or a subslice of an
@@ -213,11 +345,15 @@ where
 /// * `K` is a type implementing `Kind`. This represents the kind identity of the text. Every
 ///   text kind has an associated `Check` type to determine if a given value is valid.
 /// * `D` is a type providing `Dynamic` storage for the text. This is something like `String`,
-///   `Arc<String>` or `Rc<String>`.
+///   `Box<str>`, `Arc<String>` or `Rc<String>`.
 ///
 /// Special constructors for `&'static str` values are available that allow avoiding dynamic
 /// storage where possible.
 ///
+/// Equality and ordering only consider the stored value. Texts can be compared with any
+/// `AsRef<str>` value, which includes texts of other kinds and storages. An `Identifier` and a
+/// `Title` holding the same value are equal.
+///
 /// # Examples
 ///
 /// Basic usage:
@@ -242,7 +378,7 @@ where
 /// # }
 /// ```
 pub struct Text<K, D> {
-    _kind: marker::PhantomData<K>,
+    _kind: marker::PhantomData<fn() -> K>,
     data: Data<D>,
 }
 
@@ -318,6 +454,134 @@ where
         })
     }
 
+    /// Attempt to construct this text value from a `&'_ str` with an inline size limit.
+    ///
+    /// This behaves like [`try_from_str`](#method.try_from_str), but the value is only stored
+    /// in the inline buffer when it is at most `threshold` bytes long. Longer values go into
+    /// the dynamic storage `D`, for example an `Arc<String>` to share large values. The value
+    /// is only checked once.
+    ///
+    /// The buffer itself has a fixed size of [`SMALL_STRING_BUF`](constant.SMALL_STRING_BUF.html)
+    /// bytes, so thresholds above that have no effect. A threshold of `0` puts every non-empty
+    /// value into dynamic storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error<K>` without the associated value when the value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str_sso("foo", 0)?;
+    ///
+    /// assert!(text.into_data().is_dynamic());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_str_sso(value: &str, threshold: usize) -> Result<Self, Error<K>> {
+        K::Check::check(value).map_err(Error)?;
+        Ok(Text {
+            _kind: marker::PhantomData,
+            data: Data::from_str_with_threshold(value, threshold),
+        })
+    }
+
+    /// Attempt to construct this text value from a `&'_ str`, choosing the storage by length.
+    ///
+    /// Values of at most `threshold` bytes that fit into the inline buffer of
+    /// [`SMALL_STRING_BUF`](constant.SMALL_STRING_BUF.html) bytes are stored inline, larger
+    /// values go into the dynamic storage `D`. The value is only checked once. This currently
+    /// behaves exactly like [`try_from_str_sso`](#method.try_from_str_sso), but is the place
+    /// where future storage heuristics will be applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error<K>` without the associated value when the value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    /// use std::sync::Arc;
+    ///
+    /// let short: textkind::Title<Arc<String>> =
+    ///     textkind::Title::try_from_str_auto("foo", textkind::SMALL_STRING_BUF)?;
+    /// assert!(short.into_data().is_small());
+    ///
+    /// let long: textkind::Title<Arc<String>> =
+    ///     textkind::Title::try_from_str_auto(&"x".repeat(32), textkind::SMALL_STRING_BUF)?;
+    /// assert!(long.into_data().is_dynamic());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_str_auto(value: &str, threshold: usize) -> Result<Self, Error<K>> {
+        Self::try_from_str_sso(value, threshold)
+    }
+
+    /// Attempt to construct this text value from a single `char`.
+    ///
+    /// The value is always stored in the inline buffer without an allocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error<K>` without the associated value when the value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_char('A')?;
+    ///
+    /// assert_eq!(text.as_str(), "A");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_char(value: char) -> Result<Self, Error<K>> {
+        let mut buffer = [0; 4];
+        Self::try_from_str(value.encode_utf8(&mut buffer))
+    }
+
+    /// Attempt to construct this text value from a `&str` with an additional context check.
+    ///
+    /// The value is checked by the check of the kind first, then by the `C` context check
+    /// with the given context. See `ContextCheck` for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `check::AndError` containing the error of the kind check as the first
+    /// variant, or the error of the context check as the second variant.
+    pub fn try_from_str_with_context<C>(
+        value: &str,
+        context: &C::Context,
+    ) -> Result<Self, check::AndError<<K::Check as Check>::Error, C::Error>>
+    where
+        C: ContextCheck,
+    {
+        K::Check::check(value).map_err(check::AndError::Err1)?;
+        C::check_with(value, context).map_err(check::AndError::Err2)?;
+        Ok(Text {
+            _kind: marker::PhantomData,
+            data: Data::from_str(value),
+        })
+    }
+
     /// Attempt to construct this text value from a `std::borrow::Cow<'_ str>`.
     ///
     /// This method mainly exists because you sometimes already have a `std::borrow::Cow`
@@ -425,11 +689,14 @@ where
         })
     }
 
-    /// Attempt to construct this text value from an existing dynamic storage value.
+    /// Attempt to construct this text value from an iterator of characters.
+    ///
+    /// The characters are collected into a `String` before validation. Values small enough
+    /// for the inline buffer will be stored without dynamic storage.
     ///
     /// # Errors
     ///
-    /// Returns an `ErrorWithValue<K>` with the associated storage when the value is invalid.
+    /// Returns an `ErrorWithValue<K>` with the collected `String` when the value is invalid.
     ///
     /// # Examples
     ///
@@ -440,25 +707,36 @@ where
     /// # fn example() -> Result<(), Box<::std::error::Error>> {
     /// extern crate textkind;
     ///
-    /// let input = "foo".to_string();
-    /// let text = textkind::Title::try_from_dynamic(input)?;
-    /// println!("the value is {}", text);
+    /// let chars = "f o o".chars().filter(|c| !c.is_whitespace());
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_chars(chars)?;
+    ///
+    /// assert_eq!(text.as_str(), "foo");
     /// # Ok(())
     /// # }
     /// ```
-    pub fn try_from_dynamic(value: D) -> Result<Self, ErrorWithValue<K, D>> {
-        let value = error_with_value!(value, K::Check::check(value.as_str()))?;
+    pub fn try_from_chars<I>(chars: I) -> Result<Self, ErrorWithValue<K, String>>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let value: String = chars.into_iter().collect();
+        let value = error_with_value!(value, K::Check::check(&value))?;
         Ok(Text {
             _kind: marker::PhantomData,
-            data: Data::from_dynamic(D::from(value)),
+            data: owned_data(value),
         })
     }
 
-    /// Attempt to construct this text value from an existing data value.
+    /// Attempt to construct this text value from the characters of a `&'_ str` that are kept
+    /// by a filter.
+    ///
+    /// Only the characters for which `keep` returns `true` are collected into a `String`,
+    /// which is then validated like in [`try_from_chars`](#method.try_from_chars). This can be
+    /// used to sanitize input by dropping characters instead of rejecting it.
     ///
     /// # Errors
     ///
-    /// Returns an `ErrorWithValue<K>` with the associated data when the value is invalid.
+    /// Returns an `ErrorWithValue<K>` with the filtered `String` when the value is invalid.
     ///
     /// # Examples
     ///
@@ -469,28 +747,34 @@ where
     /// # fn example() -> Result<(), Box<::std::error::Error>> {
     /// extern crate textkind;
     ///
-    /// let input = "foo".to_string();
-    /// let text = textkind::Title::try_from_data(
-    ///     textkind::Data::Dynamic(input),
-    /// )?;
-    /// println!("the value is {}", text);
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str_filtered("fo o!", |c| c.is_ascii_alphanumeric())?;
+    ///
+    /// assert_eq!(text.as_str(), "foo");
     /// # Ok(())
     /// # }
     /// ```
-    pub fn try_from_data(data: Data<D>) -> Result<Self, ErrorWithValue<K, Data<D>>> {
-        let data = error_with_value!(data, K::Check::check(data.as_str()))?;
-        Ok(Text {
-            _kind: marker::PhantomData,
-            data,
-        })
+    pub fn try_from_str_filtered<F>(
+        value: &str,
+        mut keep: F,
+    ) -> Result<Self, ErrorWithValue<K, String>>
+    where
+        F: FnMut(char) -> bool,
+    {
+        Self::try_from_chars(value.chars().filter(|c| keep(*c)))
     }
 
-    /// Convert from another kind via the `ConvertFrom` trait.
+    /// Attempt to construct this text value from format arguments.
     ///
-    /// # Panics
+    /// The arguments are formatted into a `String` before validation. Values small enough
+    /// for the inline buffer will be stored without dynamic storage. When the arguments need
+    /// no formatting at runtime, like a format string without arguments, the value is stored
+    /// as a static value. See [`text_format!`](macro.text_format.html) for
+    /// a convenient way to call this.
     ///
-    /// Since this usually constructs a new text kind from an existing one, a call to this
-    /// may run assertions that may panic.
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` with the formatted `String` when the value is invalid.
     ///
     /// # Examples
     ///
@@ -501,57 +785,66 @@ where
     /// # fn example() -> Result<(), Box<::std::error::Error>> {
     /// extern crate textkind;
     ///
-    /// struct SourceKind;
-    /// struct TargetKind;
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_args(format_args!("{}_{}", "foo", 23))?;
     ///
-    /// impl textkind::Kind for SourceKind {
-    ///     type Check = textkind::check::Identifier;
-    ///     const DESCRIPTION: &'static str = "source";
-    /// }
+    /// assert_eq!(text.as_str(), "foo_23");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_args(args: fmt::Arguments) -> Result<Self, ErrorWithValue<K, String>> {
+        if let Some(value) = args.as_str() {
+            return Self::try_from_static_str(value)
+                .map_err(|error| error.with_value(value.to_string()));
+        }
+        let value = fmt::format(args);
+        let value = error_with_value!(value, K::Check::check(&value))?;
+        Ok(Text {
+            _kind: marker::PhantomData,
+            data: owned_data(value),
+        })
+    }
+
+    /// Attempt to construct this text value from UTF-8 encoded bytes.
     ///
-    /// impl textkind::Kind for TargetKind {
-    ///     type Check = textkind::check::Title;
-    ///     const DESCRIPTION: &'static str = "target";
-    /// }
+    /// This behaves like [`try_from_str`](#method.try_from_str) after decoding the bytes.
     ///
-    /// impl textkind::ConvertFrom<SourceKind> for TargetKind {
+    /// # Errors
     ///
-    ///     fn convert_from<D>(
-    ///         source: textkind::Text<SourceKind, D>,
-    ///     ) -> textkind::Text<TargetKind, D>
-    ///     where
-    ///         D: textkind::Dynamic,
-    ///     {
-    ///         textkind::Text::try_from_dynamic(source.into_dynamic())
-    ///             .map_err(|error| error.without_value())
-    ///             .expect("identifiers are always valid titles")
-    ///     }
-    /// }
+    /// Returns `TextFromBytesError::Utf8` when the bytes are not valid UTF-8, and
+    /// `TextFromBytesError::Check` when the decoded value is invalid.
     ///
-    /// let source: textkind::Text<SourceKind, String> =
-    ///     textkind::Text::try_from_string("foo".to_string())?;
+    /// # Examples
     ///
-    /// let target: textkind::Text<TargetKind, _> =
-    ///     textkind::Text::convert_from(source);
+    /// Basic usage:
     ///
-    /// println!("target value is {}", target);
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_utf8(b"foo")?;
+    /// assert_eq!(text.as_str(), "foo");
+    ///
+    /// assert!(textkind::Title::<String>::try_from_utf8(b"\xff").is_err());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn convert_from<K2>(other: Text<K2, D>) -> Self
-    where
-        K2: Kind,
-        K: ConvertFrom<K2>,
-    {
-        K::convert_from(other)
+    pub fn try_from_utf8(bytes: &[u8]) -> Result<Self, TextFromBytesError<K>> {
+        let value = str::from_utf8(bytes).map_err(TextFromBytesError::Utf8)?;
+        Self::try_from_str(value).map_err(TextFromBytesError::Check)
     }
 
-    /// Convert to another kind via the `ConvertFrom` trait.
+    /// Attempt to construct this text value from a UTF-8 encoded byte vector.
     ///
-    /// # Panics
+    /// The allocation of the vector is reused as the `String` given to
+    /// [`try_from_string`](#method.try_from_string).
     ///
-    /// Since this usually constructs a new text kind from an existing one, a call to this
-    /// may run assertions that may panic.
+    /// # Errors
+    ///
+    /// Returns `TextFromBytesError::Utf8` when the bytes are not valid UTF-8, and
+    /// `TextFromBytesError::Check` when the decoded value is invalid.
     ///
     /// # Examples
     ///
@@ -562,16 +855,243 @@ where
     /// # fn example() -> Result<(), Box<::std::error::Error>> {
     /// extern crate textkind;
     ///
-    /// struct SourceKind;
-    /// struct TargetKind;
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_utf8_vec(b"foo".to_vec())?;
+    /// assert_eq!(text.as_str(), "foo");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_utf8_vec(bytes: Vec<u8>) -> Result<Self, TextFromBytesError<K>> {
+        let value = String::from_utf8(bytes)
+            .map_err(|error| TextFromBytesError::Utf8(error.utf8_error()))?;
+        Self::try_from_string(value)
+            .map_err(|error| TextFromBytesError::Check(Error(error.0)))
+    }
+
+    /// Attempt to construct this text value from a `Box<str>`.
     ///
-    /// impl textkind::Kind for SourceKind {
-    ///     type Check = textkind::check::Identifier;
-    ///     const DESCRIPTION: &'static str = "source";
-    /// }
+    /// This constructor allows the dynamic storage to potentially take over ownership of the
+    /// boxed string slice and keep it instead of making a new allocation.
     ///
-    /// impl textkind::Kind for TargetKind {
-    ///     type Check = textkind::check::Title;
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` with the associated value when the value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let input: Box<str> = "foo".into();
+    /// let text: textkind::Title<Box<str>> =
+    ///     textkind::Title::try_from_boxed_str(input)?;
+    ///
+    /// println!("the value is {}", text);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_boxed_str(value: Box<str>) -> Result<Self, ErrorWithValue<K, Box<str>>> {
+        let value = error_with_value!(value, K::Check::check(&value))?;
+        Ok(Text {
+            _kind: marker::PhantomData,
+            data: Data::from_boxed_str(value),
+        })
+    }
+
+    /// Attempt to construct this text value from an existing dynamic storage value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` with the associated storage when the value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let input = "foo".to_string();
+    /// let text = textkind::Title::try_from_dynamic(input)?;
+    /// println!("the value is {}", text);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_dynamic(value: D) -> Result<Self, ErrorWithValue<K, D>> {
+        let value = error_with_value!(value, K::Check::check(value.as_str()))?;
+        Ok(Text {
+            _kind: marker::PhantomData,
+            data: Data::from_dynamic(D::from(value)),
+        })
+    }
+
+    /// Attempt to construct this text value from an existing data value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` with the associated data when the value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let input = "foo".to_string();
+    /// let text = textkind::Title::try_from_data(
+    ///     textkind::Data::Dynamic(input),
+    /// )?;
+    /// println!("the value is {}", text);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_data(data: Data<D>) -> Result<Self, ErrorWithValue<K, Data<D>>> {
+        let data = error_with_value!(data, K::Check::check(data.as_str()))?;
+        Ok(Text {
+            _kind: marker::PhantomData,
+            data,
+        })
+    }
+
+    /// Construct this text value from an existing data value without checking it.
+    ///
+    /// This is the unchecked counterpart to [`try_from_data`](#method.try_from_data), meant
+    /// for trusted round-trips like restoring data that was taken from a text of the same kind
+    /// via [`into_data`](#method.into_data).
+    ///
+    /// **The value is not validated in release builds.** Passing an invalid value breaks the
+    /// guarantee that every text of this kind is valid, and code relying on it can misbehave.
+    /// It does not cause undefined behavior, so this function is not `unsafe`. Use
+    /// [`try_from_data`](#method.try_from_data) for all data that doesn't come from a text of
+    /// this kind.
+    ///
+    /// # Panics
+    ///
+    /// Panics when debug assertions are enabled and the value is invalid, see
+    /// [`debug_assert_valid`](#method.debug_assert_valid).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo")?;
+    ///
+    /// let data = text.into_data();
+    /// let text: textkind::Title<String> = textkind::Title::from_data_unchecked(data);
+    /// assert_eq!(text.as_str(), "foo");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_data_unchecked(data: Data<D>) -> Self {
+        let text = Text {
+            _kind: marker::PhantomData,
+            data,
+        };
+        text.debug_assert_valid()
+    }
+
+    /// Convert from another kind via the `ConvertFrom` trait.
+    ///
+    /// # Panics
+    ///
+    /// Since this usually constructs a new text kind from an existing one, a call to this
+    /// may run assertions that may panic.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// struct SourceKind;
+    /// struct TargetKind;
+    ///
+    /// impl textkind::Kind for SourceKind {
+    ///     type Check = textkind::check::Identifier;
+    ///     const DESCRIPTION: &'static str = "source";
+    /// }
+    ///
+    /// impl textkind::Kind for TargetKind {
+    ///     type Check = textkind::check::Title;
+    ///     const DESCRIPTION: &'static str = "target";
+    /// }
+    ///
+    /// impl textkind::ConvertFrom<SourceKind> for TargetKind {
+    ///
+    ///     fn convert_from<D>(
+    ///         source: textkind::Text<SourceKind, D>,
+    ///     ) -> textkind::Text<TargetKind, D>
+    ///     where
+    ///         D: textkind::Dynamic,
+    ///     {
+    ///         textkind::Text::try_from_dynamic(source.into_dynamic())
+    ///             .map_err(|error| error.without_value())
+    ///             .expect("identifiers are always valid titles")
+    ///     }
+    /// }
+    ///
+    /// let source: textkind::Text<SourceKind, String> =
+    ///     textkind::Text::try_from_string("foo".to_string())?;
+    ///
+    /// let target: textkind::Text<TargetKind, _> =
+    ///     textkind::Text::convert_from(source);
+    ///
+    /// println!("target value is {}", target);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn convert_from<K2>(other: Text<K2, D>) -> Self
+    where
+        K2: Kind,
+        K: ConvertFrom<K2>,
+    {
+        K::convert_from(other)
+    }
+
+    /// Convert to another kind via the `ConvertFrom` trait.
+    ///
+    /// # Panics
+    ///
+    /// Since this usually constructs a new text kind from an existing one, a call to this
+    /// may run assertions that may panic.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// struct SourceKind;
+    /// struct TargetKind;
+    ///
+    /// impl textkind::Kind for SourceKind {
+    ///     type Check = textkind::check::Identifier;
+    ///     const DESCRIPTION: &'static str = "source";
+    /// }
+    ///
+    /// impl textkind::Kind for TargetKind {
+    ///     type Check = textkind::check::Title;
     ///     const DESCRIPTION: &'static str = "target";
     /// }
     ///
@@ -728,7 +1248,15 @@ where
         K2::try_convert_from(self)
     }
 
-    /// Get a `&str` view from the text value.
+    /// Re-run the kind's check on the stored value.
+    ///
+    /// All constructors already check the value, so this should never fail for texts built
+    /// through the public API. It is meant as a defensive guard after trusted constructions,
+    /// like `rkyv_support::from_archived_trusted`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error<K>` when the stored value is not valid for the kind `K`.
     ///
     /// # Examples
     ///
@@ -742,15 +1270,22 @@ where
     /// let text: textkind::Title<String> =
     ///     textkind::Title::try_from_str("foo")?;
     ///
-    /// assert_eq!(text.as_str(), "foo");
+    /// text.revalidate()?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn as_str(&self) -> &str { self.data.as_str() }
+    pub fn revalidate(&self) -> Result<(), Error<K>> {
+        K::Check::check(self.as_str()).map_err(Error)
+    }
 
-    /// Turn the text into a `String`.
+    /// Re-run the kind's check in debug builds and pass the value through.
     ///
-    /// Depending on the dynamic storage this might be extracted without causing an allocation.
+    /// This is intended as a cheap guard for `ConvertFrom` implementations asserting that a
+    /// conversion always produces valid values. In release builds this does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the value is not valid for the kind `K`.
     ///
     /// # Examples
     ///
@@ -761,23 +1296,57 @@ where
     /// # fn example() -> Result<(), Box<::std::error::Error>> {
     /// extern crate textkind;
     ///
-    /// let text: textkind::Title<String> =
-    ///     textkind::Title::try_from_str("foo")?;
+    /// struct SourceKind;
+    /// struct TargetKind;
     ///
-    /// let value = text.into_string();
-    /// assert_eq!(&value, "foo");
+    /// impl textkind::Kind for SourceKind {
+    ///     type Check = textkind::check::Title;
+    ///     const DESCRIPTION: &'static str = "source";
+    /// }
+    ///
+    /// impl textkind::Kind for TargetKind {
+    ///     type Check = textkind::check::Title;
+    ///     const DESCRIPTION: &'static str = "target";
+    /// }
+    ///
+    /// impl textkind::ConvertFrom<SourceKind> for TargetKind {
+    ///
+    ///     fn convert_from<D>(
+    ///         source: textkind::Text<SourceKind, D>,
+    ///     ) -> textkind::Text<TargetKind, D>
+    ///     where
+    ///         D: textkind::Dynamic,
+    ///     {
+    ///         source.kind_transition().debug_assert_valid()
+    ///     }
+    /// }
+    ///
+    /// let source: textkind::Text<SourceKind, String> =
+    ///     textkind::Text::try_from_str("foo")?;
+    ///
+    /// let target: textkind::Text<TargetKind, _> = source.convert_into();
+    /// assert_eq!(target.as_str(), "foo");
     /// # Ok(())
     /// # }
     /// ```
-    pub fn into_string(self) -> String { self.data.into_string() }
+    pub fn debug_assert_valid(self) -> Self {
+        if cfg!(debug_assertions) && K::Check::check(self.as_str()).is_err() {
+            panic!("invalid {} value {:?}", K::DESCRIPTION, self.as_str());
+        }
+        self
+    }
 
-    /// Turn the text into an `std::borrow::Cow<'static, str>`.
+    /// Attempt to trim surrounding whitespace from the stored value.
     ///
-    /// This will return a `std::borrow::Cow::Borrowed(&'static str)` when the stored value is
-    /// static and not in dynamic storage.
+    /// The trimmed value is checked again before it replaces the original. This is useful
+    /// with kinds using `check::WhenTrimmed` when the stored value should be the trimmed form
+    /// as well. Static values stay static, other values are stored as new small or dynamic
+    /// values when trimming removed anything.
     ///
-    /// Depending on the dynamic storage a non-static value might be extracted without
-    /// causing an allocation.
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` containing the original untrimmed text when the trimmed
+    /// value is invalid.
     ///
     /// # Examples
     ///
@@ -788,11 +1357,1010 @@ where
     /// # fn example() -> Result<(), Box<::std::error::Error>> {
     /// extern crate textkind;
     ///
-    /// // store a &'static str
-    /// let text: textkind::Title<String> =
-    ///     textkind::Title::try_from_static_str("foo")?;
+    /// struct Name;
     ///
-    /// // retrieve a &'static str
+    /// impl textkind::Kind for Name {
+    ///     type Check = textkind::check::WhenTrimmed<textkind::check::NotEmpty>;
+    ///     const DESCRIPTION: &'static str = "name";
+    /// }
+    ///
+    /// let text: textkind::Text<Name, String> =
+    ///     textkind::Text::try_from_static_str("  foo ")?;
+    ///
+    /// let text = text.try_trim()?;
+    /// assert_eq!(text.as_str(), "foo");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_trim(self) -> Result<Self, ErrorWithValue<K, Self>> {
+        let (start, end) = {
+            let value = self.as_str();
+            let start = value.len() - value.trim_start().len();
+            (start, start + value.trim().len())
+        };
+        let text = error_with_value!(self, K::Check::check(&self.as_str()[start..end]))?;
+        Ok(Text {
+            _kind: marker::PhantomData,
+            data: sub_data(text.data, start, end),
+        })
+    }
+
+    /// Attempt to truncate the stored value to at most `max` bytes.
+    ///
+    /// The cut is rounded down to the nearest character boundary, so a code point is never
+    /// split. The truncated value is checked again, since truncating can leave a value that
+    /// is invalid for the kind, like one ending in whitespace. Values that already fit are
+    /// returned unchanged with their storage preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` containing the original untruncated text when the
+    /// truncated value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("Käse")?;
+    ///
+    /// // the `ä` is two bytes long and would be split at 2 bytes
+    /// assert_eq!(text.clone().try_truncate_bytes(2)?.as_str(), "K");
+    /// assert_eq!(text.clone().try_truncate_bytes(3)?.as_str(), "Kä");
+    ///
+    /// // truncating to an empty value is not a valid title
+    /// assert!(text.try_truncate_bytes(0).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_truncate_bytes(self, max: usize) -> Result<Self, ErrorWithValue<K, Self>> {
+        if self.as_str().len() <= max {
+            return Ok(self);
+        }
+        let mut end = max;
+        while !self.as_str().is_char_boundary(end) {
+            end -= 1;
+        }
+        let text = error_with_value!(self, K::Check::check(&self.as_str()[..end]))?;
+        Ok(Text {
+            _kind: marker::PhantomData,
+            data: sub_data(text.data, 0, end),
+        })
+    }
+
+    /// Attempt to pad the start of the value with `fill` up to `width` bytes.
+    ///
+    /// The width is measured in bytes. A multi-byte `fill` character is never split, so the
+    /// padded value can exceed `width` by less than the length of one `fill` character. Values
+    /// that are already wide enough are returned unchanged. The padded value is checked
+    /// again, since padding can make a value invalid for the kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` containing the original unpadded text when the padded
+    /// value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str("x23")?;
+    ///
+    /// assert_eq!(text.clone().try_pad_start(6, '_')?.as_str(), "___x23");
+    ///
+    /// // identifiers cannot contain spaces
+    /// assert!(text.try_pad_start(6, ' ').is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_pad_start(self, width: usize, fill: char) -> Result<Self, ErrorWithValue<K, Self>> {
+        if self.as_str().len() >= width {
+            return Ok(self);
+        }
+        let mut value = String::with_capacity(width + fill.len_utf8());
+        while value.len() + self.as_str().len() < width {
+            value.push(fill);
+        }
+        value.push_str(self.as_str());
+        self.try_replace_padded(value)
+    }
+
+    /// Attempt to pad the end of the value with `fill` up to `width` bytes.
+    ///
+    /// The width is measured in bytes. A multi-byte `fill` character is never split, so the
+    /// padded value can exceed `width` by less than the length of one `fill` character. Values
+    /// that are already wide enough are returned unchanged. The padded value is checked
+    /// again, since padding can make a value invalid for the kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` containing the original unpadded text when the padded
+    /// value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str("x23")?;
+    ///
+    /// assert_eq!(text.clone().try_pad_end(6, '0')?.as_str(), "x23000");
+    ///
+    /// // identifiers cannot contain spaces
+    /// assert!(text.try_pad_end(6, ' ').is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_pad_end(self, width: usize, fill: char) -> Result<Self, ErrorWithValue<K, Self>> {
+        if self.as_str().len() >= width {
+            return Ok(self);
+        }
+        let mut value = String::with_capacity(width + fill.len_utf8());
+        value.push_str(self.as_str());
+        while value.len() < width {
+            value.push(fill);
+        }
+        self.try_replace_padded(value)
+    }
+
+    /// Attempt to append a single character to the value.
+    ///
+    /// The extended value is checked again. When it still fits into the inline buffer it is
+    /// built and stored there without a heap allocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` containing the original text when the extended value is
+    /// invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str("x23")?;
+    ///
+    /// assert_eq!(text.clone().try_push_char('4')?.as_str(), "x234");
+    ///
+    /// // identifiers cannot contain spaces
+    /// let error = text.try_push_char(' ').err().expect("invalid identifier");
+    /// assert_eq!(error.value().as_str(), "x23");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_push_char(self, c: char) -> Result<Self, ErrorWithValue<K, Self>> {
+        let len = self.as_str().len();
+        let new_len = len + c.len_utf8();
+        if new_len <= small::SMALL_STRING_BUF {
+            let mut buf = [0; small::SMALL_STRING_BUF];
+            buf[..len].copy_from_slice(self.as_str().as_bytes());
+            c.encode_utf8(&mut buf[len..new_len]);
+            let value = str::from_utf8(&buf[..new_len])
+                .expect("valid utf8 built from a str and a char");
+            error_with_value!(self, K::Check::check(value))?;
+            let small = SmallString::try_from(value)
+                .expect("length verified to fit the small string buffer");
+            return Ok(Text {
+                _kind: marker::PhantomData,
+                data: Data::Small(small),
+            });
+        }
+        let mut value = String::with_capacity(new_len);
+        value.push_str(self.as_str());
+        value.push(c);
+        self.try_replace_padded(value)
+    }
+
+    // Replaces the value after padding or appending, or returns the original on failure.
+    fn try_replace_padded(self, value: String) -> Result<Self, ErrorWithValue<K, Self>> {
+        error_with_value!(self, K::Check::check(&value))?;
+        Ok(Text {
+            _kind: marker::PhantomData,
+            data: owned_data(value),
+        })
+    }
+
+    /// Check if all characters of the value are ASCII.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("Käse")?;
+    ///
+    /// assert!(!text.is_ascii());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_ascii(&self) -> bool { self.as_str().is_ascii() }
+
+    /// Attempt to construct a text value with ASCII characters converted to lowercase.
+    ///
+    /// Only the ASCII letters `A` to `Z` are converted, all other characters stay unchanged. This
+    /// is cheaper than a full Unicode case conversion, but gives different results for
+    /// non-ASCII text. The converted value is checked again. Values without characters to
+    /// convert are cloned and keep their storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` with the converted `String` when it is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("Käse ÄÖ")?;
+    ///
+    /// assert_eq!(text.try_to_ascii_lowercase()?.as_str(), "käse ÄÖ");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_to_ascii_lowercase(&self) -> Result<Self, ErrorWithValue<K, String>> {
+        if !self.as_str().bytes().any(|b| b.is_ascii_uppercase()) {
+            return Ok(self.clone());
+        }
+        self.try_replace_owned(self.as_str().to_ascii_lowercase())
+    }
+
+    /// Attempt to construct a text value with ASCII characters converted to uppercase.
+    ///
+    /// Only the ASCII letters `a` to `z` are converted, all other characters stay unchanged. This
+    /// is cheaper than a full Unicode case conversion, but gives different results for
+    /// non-ASCII text. The converted value is checked again. Values without characters to
+    /// convert are cloned and keep their storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` with the converted `String` when it is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("Käse äö")?;
+    ///
+    /// assert_eq!(text.try_to_ascii_uppercase()?.as_str(), "KäSE äö");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_to_ascii_uppercase(&self) -> Result<Self, ErrorWithValue<K, String>> {
+        if !self.as_str().bytes().any(|b| b.is_ascii_lowercase()) {
+            return Ok(self.clone());
+        }
+        self.try_replace_owned(self.as_str().to_ascii_uppercase())
+    }
+
+    // Builds a text from a newly derived value, returning the value on failure.
+    fn try_replace_owned(&self, value: String) -> Result<Self, ErrorWithValue<K, String>> {
+        let value = error_with_value!(value, K::Check::check(&value))?;
+        Ok(Text {
+            _kind: marker::PhantomData,
+            data: owned_data(value),
+        })
+    }
+
+    /// Attempt to construct a text value by repeating this value `n` times.
+    ///
+    /// The repeated value is checked again, so kinds with a byte limit reject repetitions
+    /// that are too long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` with the repeated `String` when it is invalid.
+    ///
+    /// # Panics
+    ///
+    /// Panics like `str::repeat` if the byte length of the repeated value would overflow
+    /// `usize`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("-=")?;
+    ///
+    /// assert_eq!(text.repeat(3)?.as_str(), "-=-=-=");
+    /// assert!(text.repeat(1000).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn repeat(&self, n: usize) -> Result<Self, ErrorWithValue<K, String>> {
+        self.try_replace_owned(self.as_str().repeat(n))
+    }
+
+    /// Attempt to collapse whitespace in the value.
+    ///
+    /// Leading and trailing whitespace is removed and every internal run of whitespace is
+    /// replaced by a single space. The result is checked again. The text is returned as it is
+    /// when the value is already normalized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` with the normalized `String` when it is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo   bar")?;
+    ///
+    /// assert_eq!(text.normalize_whitespace()?.as_str(), "foo bar");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn normalize_whitespace(self) -> Result<Self, ErrorWithValue<K, String>> {
+        let value = self.as_str().split_whitespace().collect::<Vec<_>>().join(" ");
+        if value == self.as_str() {
+            return Ok(self);
+        }
+        self.try_replace_owned(value)
+    }
+
+    /// Attempt to convert the value to title case.
+    ///
+    /// The first character of every whitespace separated word is converted to uppercase and
+    /// the remaining characters to lowercase. Whitespace is kept as it is. This uses the
+    /// Unicode uppercase and lowercase mappings, so a character might expand into more than
+    /// one, and special title case forms are not applied. The result is checked again. The
+    /// text is returned as it is when the value is already in title case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorWithValue<K>` with the converted `String` when it is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("the lord of the rings")?;
+    ///
+    /// assert_eq!(text.to_titlecase()?.as_str(), "The Lord Of The Rings");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_titlecase(self) -> Result<Self, ErrorWithValue<K, String>> {
+        let mut value = String::with_capacity(self.len());
+        let mut word_start = true;
+        for c in self.as_str().chars() {
+            if c.is_whitespace() {
+                value.push(c);
+                word_start = true;
+            } else if word_start {
+                value.extend(c.to_uppercase());
+                word_start = false;
+            } else {
+                value.extend(c.to_lowercase());
+            }
+        }
+        if value == self.as_str() {
+            return Ok(self);
+        }
+        self.try_replace_owned(value)
+    }
+
+    /// Check if the value would also be valid for another kind.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo")?;
+    ///
+    /// assert!(text.is_valid_as::<textkind::kind::Identifier>());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_valid_as<K2>(&self) -> bool
+    where
+        K2: Kind,
+    {
+        self.check_as::<K2>().is_ok()
+    }
+
+    /// Check the value against another kind without converting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error<K2>` when the value is invalid for the other kind.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo bar")?;
+    ///
+    /// assert!(text.check_as::<textkind::kind::Identifier>().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn check_as<K2>(&self) -> Result<(), Error<K2>>
+    where
+        K2: Kind,
+    {
+        K2::Check::check(self.as_str()).map_err(Error)
+    }
+
+    /// Get a `&str` view from the text value.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo")?;
+    ///
+    /// assert_eq!(text.as_str(), "foo");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_str(&self) -> &str { self.data.as_str() }
+
+    /// Get an iterator over the bytes of the value.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str("foo")?;
+    ///
+    /// assert_eq!(text.bytes().collect::<Vec<_>>(), b"foo");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bytes(&self) -> str::Bytes<'_> { self.as_str().bytes() }
+
+    /// Get an iterator over the chars of the value.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str("foo")?;
+    ///
+    /// assert_eq!(text.chars().rev().collect::<String>(), "oof");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn chars(&self) -> str::Chars<'_> { self.as_str().chars() }
+
+    /// Get an iterator over the chars of the value and their byte indices.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("Käse")?;
+    ///
+    /// assert_eq!(text.char_indices().nth(2), Some((3, 's')));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn char_indices(&self) -> str::CharIndices<'_> { self.as_str().char_indices() }
+
+    /// Get the byte at the given byte index.
+    ///
+    /// Returns `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str("foo")?;
+    ///
+    /// assert_eq!(text.byte_at(0), Some(b'f'));
+    /// assert_eq!(text.byte_at(3), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn byte_at(&self, index: usize) -> Option<u8> {
+        self.as_str().as_bytes().get(index).cloned()
+    }
+
+    /// Get the character at the given character index.
+    ///
+    /// The index counts characters, not bytes. Returns `None` if the value has fewer
+    /// characters.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str("foo")?;
+    ///
+    /// assert_eq!(text.char_at(1), Some('o'));
+    /// assert_eq!(text.char_at(3), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn char_at(&self, index: usize) -> Option<char> { self.as_str().chars().nth(index) }
+
+    /// Get the first character of the value.
+    ///
+    /// Returns `None` if the value is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str("foo")?;
+    ///
+    /// assert_eq!(text.first_char(), Some('f'));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn first_char(&self) -> Option<char> { self.as_str().chars().next() }
+
+    /// Get the last character of the value.
+    ///
+    /// Returns `None` if the value is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str("foo")?;
+    ///
+    /// assert_eq!(text.last_char(), Some('o'));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn last_char(&self) -> Option<char> { self.as_str().chars().next_back() }
+
+    /// Compare the value with a text of any kind and storage.
+    ///
+    /// This compares the `&str` values and ignores kinds and dynamic storages.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    /// use std::cmp::Ordering;
+    /// use std::rc::Rc;
+    ///
+    /// let title: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo")?;
+    /// let identifier: textkind::Identifier<Rc<String>> =
+    ///     textkind::Identifier::try_from_str("bar")?;
+    ///
+    /// assert_eq!(title.cmp_str(&identifier), Ordering::Greater);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cmp_str<K2, D2>(&self, other: &Text<K2, D2>) -> cmp::Ordering
+    where
+        K2: Kind,
+        D2: Dynamic,
+    {
+        self.as_str().cmp(other.as_str())
+    }
+
+    /// Get the byte length of the prefix this value shares with another text of the same kind.
+    ///
+    /// The prefix is compared by characters, so the length always ends on a character
+    /// boundary of both values.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    /// use std::rc::Rc;
+    ///
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str("foobar")?;
+    /// let other: textkind::Identifier<Rc<String>> =
+    ///     textkind::Identifier::try_from_str("foobaz")?;
+    ///
+    /// assert_eq!(text.common_prefix_len(&other), 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn common_prefix_len<D2>(&self, other: &Text<K, D2>) -> usize
+    where
+        D2: Dynamic,
+    {
+        let value = self.as_str();
+        value.char_indices()
+            .zip(other.as_str().chars())
+            .find(|&((_, c), other_c)| c != other_c)
+            .map(|((index, _), _)| index)
+            .unwrap_or_else(|| cmp::min(value.len(), other.as_str().len()))
+    }
+
+    /// Get the prefix this value shares with another text of the same kind.
+    ///
+    /// See [`common_prefix_len`](#method.common_prefix_len) for details.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str("foobar")?;
+    /// let other: textkind::Identifier<String> =
+    ///     textkind::Identifier::try_from_str("foobaz")?;
+    ///
+    /// assert_eq!(text.common_prefix(&other), "fooba");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn common_prefix<D2>(&self, other: &Text<K, D2>) -> &str
+    where
+        D2: Dynamic,
+    {
+        &self.as_str()[..self.common_prefix_len(other)]
+    }
+
+    /// Compare the value with another text of the same kind after normalization.
+    ///
+    /// Both values are normalized to Unicode NFC and lowercased before comparison. This treats
+    /// differently composed or cased spellings of the same text as equal. The `PartialEq`
+    /// implementation stays byte-exact.
+    ///
+    /// This requires the `unicode-normalization` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let composed: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("Caf\u{e9}")?;
+    /// let decomposed: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("CAFE\u{301}")?;
+    ///
+    /// assert!(composed != decomposed);
+    /// assert!(composed.eq_normalized(&decomposed));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unicode-normalization")]
+    pub fn eq_normalized<D2>(&self, other: &Text<K, D2>) -> bool
+    where
+        D2: Dynamic,
+    {
+        use unicode_normalization::UnicodeNormalization;
+
+        self.as_str().nfc().flat_map(char::to_lowercase)
+            .eq(other.as_str().nfc().flat_map(char::to_lowercase))
+    }
+
+    /// Get a display adapter writing at most `max_chars` characters of the value.
+    ///
+    /// When the value is longer, the adapter writes the first `max_chars` characters followed
+    /// by `…`. The limit counts characters, not bytes, so multi-byte characters are never
+    /// split.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo bar")?;
+    ///
+    /// assert_eq!(text.display_truncated(3).to_string(), "foo…");
+    /// assert_eq!(text.display_truncated(7).to_string(), "foo bar");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn display_truncated<'a>(&'a self, max_chars: usize) -> impl fmt::Display + 'a {
+        Truncated {
+            value: self.as_str(),
+            max_chars,
+        }
+    }
+
+    /// Write the value into a `std::fmt::Write` sink.
+    ///
+    /// This writes the stored `&str` directly without going through the formatting
+    /// machinery.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo")?;
+    ///
+    /// let mut buffer = "value: ".to_string();
+    /// text.write_to(&mut buffer)?;
+    /// assert_eq!(buffer, "value: foo");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_to<W>(&self, sink: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        sink.write_str(self.as_str())
+    }
+
+    /// Get a copy of the value with characters escaped by `str::escape_default`.
+    ///
+    /// This escapes everything except printable ASCII characters and is useful to safely
+    /// log values.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("Fancy \"Käse\"")?;
+    ///
+    /// assert_eq!(text.escape_default(), "Fancy \\\"K\\u{e4}se\\\"");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn escape_default(&self) -> String { self.as_str().escape_default().to_string() }
+
+    /// Get a copy of the value with characters escaped by `str::escape_debug`.
+    ///
+    /// Unlike `escape_default` this keeps printable non-ASCII characters unescaped.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("Fancy \"Käse\"")?;
+    ///
+    /// assert_eq!(text.escape_debug(), "Fancy \\\"Käse\\\"");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn escape_debug(&self) -> String { self.as_str().escape_debug().to_string() }
+
+    /// Replace the contents of `buf` with the value, reusing its capacity.
+    ///
+    /// This avoids a new allocation for every copy when materializing many values in a loop.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo")?;
+    ///
+    /// let mut buf = "previous content".to_string();
+    /// text.clone_into_string_buf(&mut buf);
+    /// assert_eq!(buf, "foo");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clone_into_string_buf(&self, buf: &mut String) {
+        self.data.clone_into_string_buf(buf)
+    }
+
+    /// Turn the text into a `String`.
+    ///
+    /// Depending on the dynamic storage this might be extracted without causing an allocation.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo")?;
+    ///
+    /// let value = text.into_string();
+    /// assert_eq!(&value, "foo");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_string(self) -> String { self.data.into_string() }
+
+    /// Get a `std::borrow::Cow<str>` borrowing the value.
+    ///
+    /// This always returns `std::borrow::Cow::Borrowed` and never allocates. Use
+    /// [`into_static_str_cow`](#method.into_static_str_cow) when the result has to outlive the
+    /// text.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    /// use std::borrow::Cow;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo")?;
+    ///
+    /// let cow: Cow<str> = text.as_cow();
+    /// assert_eq!(cow, Cow::Borrowed("foo"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_cow(&self) -> borrow::Cow<'_, str> { borrow::Cow::Borrowed(self.as_str()) }
+
+    /// Turn the text into an `std::borrow::Cow<'static, str>`.
+    ///
+    /// This will return a `std::borrow::Cow::Borrowed(&'static str)` when the stored value is
+    /// static and not in dynamic storage.
+    /// Small and dynamic values are turned into an owned `String`. See
+    /// [`as_cow`](#method.as_cow) for the borrowing counterpart, which never allocates.
+    ///
+    /// Depending on the dynamic storage a non-static value might be extracted without
+    /// causing an allocation.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// // store a &'static str
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_static_str("foo")?;
+    ///
+    /// // retrieve a &'static str
     /// let value = text.into_static_str_cow();
     /// # Ok(())
     /// # }
@@ -801,6 +2369,35 @@ where
         self.data.into_static_str_cow()
     }
 
+    /// Turn the text into an `std::borrow::Cow<'static, str>`.
+    ///
+    /// This is an alias for [`into_static_str_cow`](#method.into_static_str_cow). Static values
+    /// stay borrowed, other values become an owned `String`. Use [`as_cow`](#method.as_cow) to
+    /// borrow the value without consuming the text or allocating.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    /// use std::borrow::Cow;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_static_str("foo")?;
+    /// assert_eq!(text.into_cow(), Cow::Borrowed("foo"));
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo")?;
+    /// let cow: Cow<'static, str> = text.into_cow();
+    /// assert_eq!(cow, Cow::<str>::Owned("foo".to_string()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_cow(self) -> borrow::Cow<'static, str> { self.into_static_str_cow() }
+
     /// Extract the dynamic storage value, optionally creating one if the value is static.
     ///
     /// # Examples
@@ -829,6 +2426,31 @@ where
         self.data.into_dynamic()
     }
 
+    /// Borrow the dynamic storage value, if the value is held in dynamic storage.
+    ///
+    /// Returns `None` for static and small values.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    /// use std::sync::Arc;
+    ///
+    /// let shared_string = Arc::new("foo".to_string());
+    /// let data = textkind::Data::from_dynamic(shared_string.clone());
+    /// let text = textkind::Title::try_from_data(data)?;
+    ///
+    /// let stored = text.as_dynamic().expect("dynamic storage");
+    /// assert_eq!(Arc::strong_count(stored), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_dynamic(&self) -> Option<&D> { self.data.as_dynamic() }
+
     /// Extract the data value.
     ///
     /// # Examples
@@ -848,14 +2470,38 @@ where
     ///     textkind::Data::Dynamic(shared_string),
     /// )?;
     ///
-    /// // extract the shared string
-    /// let value = text.into_data();
+    /// // extract the shared string
+    /// let value = text.into_data();
+    ///
+    /// assert_eq!(value.as_str(), "foo");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_data(self) -> Data<D> { self.data }
+
+    /// Get the kind of storage holding the value.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    /// use textkind::StorageKind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_static_str("foo")?;
+    /// assert_eq!(text.storage_kind(), StorageKind::Static);
     ///
-    /// assert_eq!(value.as_str(), "foo");
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("foo")?;
+    /// assert_eq!(text.storage_kind(), StorageKind::Small);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn into_data(self) -> Data<D> { self.data }
+    pub fn storage_kind(&self) -> StorageKind { self.data.storage_kind() }
 
     /// Attempt to transition to another kind.
     ///
@@ -944,9 +2590,142 @@ where
         }
     }
 
+    /// Attempt to construct a text of another kind from a byte range of the value.
+    ///
+    /// The subslice is checked against the target kind. Static values stay static, other
+    /// values are stored as new small or dynamic values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SliceError::InvalidRange` when the range is out of bounds or not on `char`
+    /// boundaries, and `SliceError::Invalid` when the subslice is not valid for `K2`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_static_str("env:HOME")?;
+    ///
+    /// let name: textkind::EnvVarName<String> = text.checked_slice(4..8)?;
+    /// assert_eq!(name.as_str(), "HOME");
+    ///
+    /// assert!(text.checked_slice::<textkind::kind::EnvVarName>(4..20).is_err());
+    /// assert!(text.checked_slice::<textkind::kind::EnvVarName>(3..8).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn checked_slice<K2>(&self, range: ops::Range<usize>) -> Result<Text<K2, D>, SliceError<K2>>
+    where
+        K2: Kind,
+    {
+        let ops::Range { start, end } = range;
+        if self.as_str().get(start..end).is_none() {
+            return Err(SliceError::InvalidRange { start, end });
+        }
+        self.try_sub_text(start, end).map_err(SliceError::Invalid)
+    }
+
+    // Checks a subslice on char boundaries against another kind, keeping static values static.
+    fn try_sub_text<K2>(&self, start: usize, end: usize) -> Result<Text<K2, D>, Error<K2>>
+    where
+        K2: Kind,
+    {
+        let value = &self.as_str()[start..end];
+        K2::Check::check(value).map_err(Error)?;
+        let data = match self.data {
+            Data::Static(value) => Data::Static(&value[start..end]),
+            _ => Data::from_str(value),
+        };
+        Ok(Text {
+            _kind: marker::PhantomData,
+            data,
+        })
+    }
+
+    /// Attempt to split the value at the first `delim` into texts of two other kinds.
+    ///
+    /// The part before the delimiter is checked against `K1`, the part after it against `K2`.
+    /// Parts of static values stay static. Other parts are stored as new small or dynamic
+    /// values, so each part can allocate.
+    ///
+    /// Returns `None` when the value doesn't contain the delimiter.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SplitError::Left` when the first part is invalid, and `SplitError::Right`
+    /// when only the second part is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    /// use textkind::kind;
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_str("name=Some Value")?;
+    ///
+    /// let (key, value) = text
+    ///     .try_split_once_as::<kind::Identifier, kind::Title>('=')
+    ///     .expect("delimiter present")?;
+    ///
+    /// assert_eq!(key.as_str(), "name");
+    /// assert_eq!(value.as_str(), "Some Value");
+    ///
+    /// assert!(text.try_split_once_as::<kind::Identifier, kind::Title>(':').is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_split_once_as<K1, K2>(
+        &self,
+        delim: char,
+    ) -> Option<SplitResult<K1, K2, D>>
+    where
+        K1: Kind,
+        K2: Kind,
+    {
+        let index = self.as_str().find(delim)?;
+        let left = match self.try_sub_text(0, index) {
+            Ok(left) => left,
+            Err(error) => return Some(Err(SplitError::Left(error))),
+        };
+        let right = match self.try_sub_text(index + delim.len_utf8(), self.as_str().len()) {
+            Ok(right) => right,
+            Err(error) => return Some(Err(SplitError::Right(error))),
+        };
+        Some(Ok((left, right)))
+    }
+
+    /// Transition to another kind the current kind implies.
+    ///
+    /// The value is only checked again when debug assertions are enabled. See
+    /// [`Implies`](trait.Implies.html) for details.
+    pub fn imply_kind<K2>(self) -> Text<K2, D>
+    where
+        K: Implies<K2>,
+        K2: Kind,
+    {
+        let text: Text<K2, D> = Text {
+            _kind: marker::PhantomData,
+            data: self.data,
+        };
+        text.debug_assert_valid()
+    }
+
     /// Transition to another dynamic storage.
     ///
-    /// The text kind will stay the same.
+    /// The text kind will stay the same. Between the provided storages, the `String` of a
+    /// dynamic value is moved rather than copied, unless it is shared. Turning it into a
+    /// `Box<str>` can still reallocate to drop excess capacity.
     ///
     /// # Examples
     ///
@@ -980,6 +2759,114 @@ where
             data: self.data.convert(),
         }
     }
+
+    /// Release spare capacity of the dynamic storage.
+    ///
+    /// This uses [`Dynamic::shrink`](trait.Dynamic.html#method.shrink), which only has an
+    /// effect for `String` storage among the provided storages. Static and small values are
+    /// returned as they are.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    ///
+    /// let mut value = String::with_capacity(64);
+    /// value.push_str("a title that is stored dynamically");
+    ///
+    /// let text: textkind::Title<String> =
+    ///     textkind::Title::try_from_string(value)?;
+    ///
+    /// let value = text.shrink().into_string();
+    /// assert_eq!(value.capacity(), value.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn shrink(self) -> Self {
+        Text {
+            _kind: marker::PhantomData,
+            data: self.data.shrink(),
+        }
+    }
+
+    /// Clone the text into `String` storage.
+    ///
+    /// Static and small values keep their storage and don't allocate. Dynamic values are
+    /// copied into a new `String` directly instead of going through `Dynamic::into_string`
+    /// on a clone of the dynamic storage.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    /// use std::rc::Rc;
+    ///
+    /// let shared: textkind::Title<Rc<String>> =
+    ///     textkind::Title::try_from_str("foo")?;
+    ///
+    /// let owned: textkind::Title<String> = shared.to_string_storage();
+    /// assert_eq!(owned, shared);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_string_storage(&self) -> Text<K, String> {
+        let data = match self.data {
+            Data::Static(value) => Data::Static(value),
+            Data::Dynamic(ref dynamic) => Data::from_string(dynamic.as_str().to_string()),
+            Data::Small(small) => Data::Small(small),
+        };
+        Text {
+            _kind: marker::PhantomData,
+            data,
+        }
+    }
+}
+
+// The storage parameter is fixed here so calls through aliases like `Title` don't leave it
+// uninferred. The storage of the result is chosen by the caller.
+impl<K> Text<K, String>
+where
+    K: Kind,
+{
+    /// Attempt to construct a text value with the dynamic storage chosen at the call site.
+    ///
+    /// This is like [`try_from_str`](#method.try_from_str) but allows specifying the dynamic
+    /// storage via turbofish on an alias without a storage parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error<K>` without the associated value when the value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// extern crate textkind;
+    /// use std::sync::Arc;
+    ///
+    /// let text = textkind::Title::parse_with::<Arc<String>>("foo")?;
+    ///
+    /// assert_eq!(text.into_dynamic(), Arc::new("foo".to_string()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_with<D2>(value: &str) -> Result<Text<K, D2>, Error<K>>
+    where
+        D2: Dynamic,
+    {
+        Text::try_from_str(value)
+    }
 }
 
 impl<K, D> Clone for Text<K, D>
@@ -1022,6 +2909,8 @@ where
     K: Kind,
     D: Dynamic,
 {
+    // Delegating to `str` keeps width, fill and alignment. Precision truncates by `char`
+    // count, so it never splits a multibyte character.
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt::Display::fmt(self.as_str(), fmt)
     }
@@ -1035,12 +2924,22 @@ where
     fn as_ref(&self) -> &str { self.as_str() }
 }
 
+impl<K, D> AsRef<[u8]> for Text<K, D>
+where
+    K: Kind,
+    D: Dynamic,
+{
+    fn as_ref(&self) -> &[u8] { self.as_str().as_bytes() }
+}
+
 impl<K, D> Eq for Text<K, D>
 where
     K: Kind,
     D: Dynamic,
 {}
 
+// This also covers texts of other kinds and storages. A separate impl for `Text<K2, D2>`
+// would overlap with this one.
 impl<K, D, T> PartialEq<T> for Text<K, D>
 where
     K: Kind,
@@ -1052,6 +2951,36 @@ where
     }
 }
 
+impl<K, D> PartialEq<Text<K, D>> for str
+where
+    K: Kind,
+    D: Dynamic,
+{
+    fn eq(&self, other: &Text<K, D>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<K, D> PartialEq<Text<K, D>> for &str
+where
+    K: Kind,
+    D: Dynamic,
+{
+    fn eq(&self, other: &Text<K, D>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl<K, D> PartialEq<Text<K, D>> for String
+where
+    K: Kind,
+    D: Dynamic,
+{
+    fn eq(&self, other: &Text<K, D>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
 impl<K, D> Ord for Text<K, D>
 where
     K: Kind,
@@ -1095,3 +3024,135 @@ where
 
     fn deref(&self) -> &str { self.as_str() }
 }
+
+/// Attempt to join values with a separator into a text value.
+///
+/// This is the validated equivalent of `slice::join`. The joined value is checked as a whole,
+/// an empty iterator results in an empty value being checked.
+///
+/// # Errors
+///
+/// Returns an `ErrorWithValue<K>` with the joined `String` when the value is invalid.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// extern crate textkind;
+///
+/// let path: textkind::Title<String> = textkind::join(vec!["foo", "bar", "baz"], ".")?;
+/// assert_eq!(path.as_str(), "foo.bar.baz");
+///
+/// let empty: Result<textkind::Title<String>, _> = textkind::join(Vec::<&str>::new(), ".");
+/// assert!(empty.is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn join<K, D, I>(items: I, sep: &str) -> Result<Text<K, D>, ErrorWithValue<K, String>>
+where
+    K: Kind,
+    D: Dynamic,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut value = String::new();
+    for (index, item) in items.into_iter().enumerate() {
+        if index > 0 {
+            value.push_str(sep);
+        }
+        value.push_str(item.as_ref());
+    }
+    let value = error_with_value!(value, K::Check::check(&value))?;
+    Ok(Text {
+        _kind: marker::PhantomData,
+        data: owned_data(value),
+    })
+}
+
+/// Stably sort texts by the bytes of their values.
+///
+/// This makes the ordering explicit and doesn't depend on the locale. Texts with equal values
+/// keep their relative order.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// extern crate textkind;
+///
+/// let mut texts: Vec<textkind::Title<String>> = vec![
+///     textkind::Title::try_from_str("b")?,
+///     textkind::Title::try_from_str("B")?,
+///     textkind::Title::try_from_str("a")?,
+/// ];
+///
+/// textkind::sort_texts(&mut texts);
+/// assert_eq!(texts, ["B", "a", "b"]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn sort_texts<K, D>(texts: &mut [Text<K, D>])
+where
+    K: Kind,
+    D: Dynamic,
+{
+    texts.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+}
+
+/// Stably sort texts by the bytes of their values, ignoring ASCII case.
+///
+/// Texts whose values only differ in ASCII case keep their relative order.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// extern crate textkind;
+///
+/// let mut texts: Vec<textkind::Title<String>> = vec![
+///     textkind::Title::try_from_str("b")?,
+///     textkind::Title::try_from_str("B")?,
+///     textkind::Title::try_from_str("a")?,
+/// ];
+///
+/// textkind::sort_texts_ci(&mut texts);
+/// assert_eq!(texts, ["a", "b", "B"]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn sort_texts_ci<K, D>(texts: &mut [Text<K, D>])
+where
+    K: Kind,
+    D: Dynamic,
+{
+    texts.sort_by(|a, b| {
+        let a = a.as_str().bytes().map(|byte| byte.to_ascii_lowercase());
+        let b = b.as_str().bytes().map(|byte| byte.to_ascii_lowercase());
+        a.cmp(b)
+    });
+}
+
+// Display adapter used by `Text::display_truncated`.
+struct Truncated<'a> {
+    value: &'a str,
+    max_chars: usize,
+}
+
+impl<'a> fmt::Display for Truncated<'a> {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.value.char_indices().nth(self.max_chars) {
+            Some((index, _)) => write!(fmt, "{}\u{2026}", &self.value[..index]),
+            None => fmt.write_str(self.value),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Rkyv integration.
+//!
+//! Texts are archived as an `rkyv::string::ArchivedString` containing the value, so archived
+//! texts can be read in place via `as_str`.
+//!
+//! The stored kind is not part of the archive, so archived values are validated again when
+//! they are turned back into texts:
+//!
+//! * The `rkyv::Deserialize` implementation and [`try_from_archived`](fn.try_from_archived.html)
+//!   always run the check of the kind.
+//! * [`from_archived_trusted`](fn.from_archived_trusted.html) skips the check in release
+//!   builds. It is intended for archives that were produced from valid texts, like a local
+//!   cache written by the same program.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! # fn main() { example().expect("no errors") }
+//! # fn example() -> Result<(), Box<::std::error::Error>> {
+//! extern crate rkyv;
+//! extern crate textkind;
+//!
+//! let text = textkind::Title::<String>::try_from_str("foo")?;
+//!
+//! let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&text)?;
+//! let restored: textkind::Title<String> =
+//!     rkyv::from_bytes::<_, rkyv::rancor::Error>(&bytes)?;
+//!
+//! assert_eq!(restored.as_str(), "foo");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::error;
+use std::fmt;
+use std::marker;
+
+use rkyv;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::string::{ArchivedString, StringResolver};
+
+// Carries the explanation of a failed check through rkyv's error type.
+#[derive(Debug)]
+struct Error(String);
+
+impl error::Error for Error {
+
+    fn description(&self) -> &str { "invalid archived text" }
+}
+
+impl fmt::Display for Error {
+
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(&self.0)
+    }
+}
+
+impl<K, D> rkyv::Archive for ::Text<K, D>
+where
+    K: ::Kind,
+    D: ::Dynamic,
+{
+    type Archived = ArchivedString;
+    type Resolver = StringResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+        ArchivedString::resolve_from_str(self.as_str(), resolver, out);
+    }
+}
+
+impl<K, D, S> rkyv::Serialize<S> for ::Text<K, D>
+where
+    K: ::Kind,
+    D: ::Dynamic,
+    S: Fallible + rkyv::ser::Writer + ?Sized,
+    S::Error: Source,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_str(self.as_str(), serializer)
+    }
+}
+
+impl<K, D, De> rkyv::Deserialize<::Text<K, D>, De> for ArchivedString
+where
+    K: ::Kind,
+    D: ::Dynamic,
+    De: Fallible + ?Sized,
+    De::Error: Source,
+    <<K as ::Kind>::Check as ::Check>::Error: fmt::Display,
+{
+    fn deserialize(&self, _: &mut De) -> Result<::Text<K, D>, De::Error> {
+        try_from_archived(self).map_err(|error| De::Error::new(Error(error.explain())))
+    }
+}
+
+/// Attempt to construct a text value from an archived string.
+///
+/// # Errors
+///
+/// Returns an `Error<K>` when the archived value is invalid for the kind.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// extern crate rkyv;
+/// extern crate textkind;
+///
+/// let text = textkind::Title::<String>::try_from_str("foo")?;
+/// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&text)?;
+/// let archived = rkyv::access::<rkyv::string::ArchivedString, rkyv::rancor::Error>(&bytes)?;
+///
+/// let restored: textkind::Identifier<String> =
+///     textkind::rkyv_support::try_from_archived(archived)?;
+/// assert_eq!(restored.as_str(), "foo");
+/// # Ok(())
+/// # }
+/// ```
+pub fn try_from_archived<K, D>(archived: &ArchivedString) -> Result<::Text<K, D>, ::Error<K>>
+where
+    K: ::Kind,
+    D: ::Dynamic,
+{
+    ::Text::try_from_str(archived.as_str())
+}
+
+/// Construct a text value from a trusted archived string.
+///
+/// The check of the kind only runs when debug assertions are enabled, see
+/// `Text::debug_assert_valid`.
+///
+/// # Panics
+///
+/// Panics if debug assertions are enabled and the archived value is invalid.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// extern crate rkyv;
+/// extern crate textkind;
+///
+/// let text = textkind::Title::<String>::try_from_str("foo")?;
+/// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&text)?;
+/// let archived = rkyv::access::<rkyv::string::ArchivedString, rkyv::rancor::Error>(&bytes)?;
+///
+/// let restored: textkind::Title<String> =
+///     textkind::rkyv_support::from_archived_trusted(archived);
+/// assert_eq!(restored.as_str(), "foo");
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_archived_trusted<K, D>(archived: &ArchivedString) -> ::Text<K, D>
+where
+    K: ::Kind,
+    D: ::Dynamic,
+{
+    let text = ::Text {
+        _kind: marker::PhantomData,
+        data: ::Data::from_str(archived.as_str()),
+    };
+    text.debug_assert_valid()
+}
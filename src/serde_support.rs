@@ -1,22 +1,175 @@
+//! Serde integration.
+//!
+//! Besides the `Serialize` and `Deserialize` implementations for `Text`, this module provides
+//! `serialize` and `deserialize` functions following the conventions of serde's `with` field
+//! attribute:
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Book {
+//!     #[serde(with = "textkind::serde_support")]
+//!     title: textkind::Title<String>,
+//! }
+//! ```
+//!
+//! Texts only deserialize from strings. The `FromNumber` wrapper and the
+//! `deserialize_from_number` function additionally accept integers, which are validated in
+//! their decimal string form.
 
 use std::fmt;
+use std::marker;
 
 use serde;
 
-struct Error<K>
+struct Visitor<K, D> {
+    _text: marker::PhantomData<fn() -> ::Text<K, D>>,
+}
+
+impl<'de, K, D> serde::de::Visitor<'de> for Visitor<K, D>
 where
-    K: ::Kind
+    K: ::Kind,
+    D: ::Dynamic,
+    <<K as ::Kind>::Check as ::Check>::Error: fmt::Display,
 {
-    inner: ::Error<K>,
+    type Value = ::Text<K, D>;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "a string for {}", K::DESCRIPTION)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        ::Text::try_from_str(value).map_err(|error| E::custom(error.explain()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        ::Text::try_from_string(value)
+            .map_err(|error| E::custom(error.without_value().explain()))
+    }
 }
 
-impl<K> fmt::Display for Error<K>
+struct NumberVisitor<K, D> {
+    _text: marker::PhantomData<fn() -> ::Text<K, D>>,
+}
+
+impl<'de, K, D> serde::de::Visitor<'de> for NumberVisitor<K, D>
 where
     K: ::Kind,
+    D: ::Dynamic,
     <<K as ::Kind>::Check as ::Check>::Error: fmt::Display,
 {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        write!(fmt, "{} because {}", self.inner, self.inner.0)
+    type Value = ::Text<K, D>;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "a string or integer for {}", K::DESCRIPTION)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Visitor { _text: marker::PhantomData }.visit_str(value)
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Visitor { _text: marker::PhantomData }.visit_string(value)
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_string(value.to_string())
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_string(value.to_string())
+    }
+
+    fn visit_i128<E>(self, value: i128) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_string(value.to_string())
+    }
+
+    fn visit_u128<E>(self, value: u128) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_string(value.to_string())
+    }
+}
+
+/// Deserialization wrapper accepting integers in place of strings.
+///
+/// Integers are turned into their decimal string form and validated like strings. Floats and
+/// other types are still rejected. The wrapper serializes the text as a string.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// extern crate textkind;
+/// extern crate serde_json;
+///
+/// use textkind::serde_support::FromNumber;
+///
+/// let id: FromNumber<textkind::IdentifierLax<String>> = serde_json::from_str("42")?;
+/// assert_eq!(id.into_inner().as_str(), "42");
+///
+/// let id: FromNumber<textkind::IdentifierLax<String>> = serde_json::from_str("\"foo\"")?;
+/// assert_eq!(id.0.as_str(), "foo");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FromNumber<T>(pub T);
+
+impl<T> FromNumber<T> {
+
+    /// Extract the wrapped value.
+    pub fn into_inner(self) -> T { self.0 }
+}
+
+impl<'de, K, D> serde::Deserialize<'de> for FromNumber<::Text<K, D>>
+where
+    K: ::Kind,
+    D: ::Dynamic,
+    <<K as ::Kind>::Check as ::Check>::Error: fmt::Display,
+{
+    fn deserialize<T>(deserializer: T) -> Result<Self, T::Error>
+    where
+        T: serde::Deserializer<'de>,
+    {
+        deserialize_from_number(deserializer).map(FromNumber)
+    }
+}
+
+impl<K, D> serde::Serialize for FromNumber<::Text<K, D>>
+where
+    K: ::Kind,
+    D: ::Dynamic,
+{
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: serde::Serializer,
+    {
+        serialize(&self.0, serializer)
     }
 }
 
@@ -24,17 +177,13 @@ impl<'de, K, D> serde::Deserialize<'de> for ::Text<K, D>
 where
     K: ::Kind,
     D: ::Dynamic,
-    D: serde::Deserialize<'de>,
     <<K as ::Kind>::Check as ::Check>::Error: fmt::Display,
 {
     fn deserialize<T>(deserializer: T) -> Result<::Text<K, D>, T::Error>
     where
         T: serde::Deserializer<'de>,
     {
-        let value = D::deserialize(deserializer)?;
-        ::Text::try_from_dynamic(value).map_err(|error| serde::de::Error::custom(Error {
-            inner: error.without_value(),
-        }))
+        deserialize(deserializer)
     }
 }
 
@@ -47,6 +196,104 @@ where
     where
         T: serde::Serializer,
     {
-        serializer.serialize_str(self.as_str())
+        serialize(self, serializer)
     }
 }
+
+/// Serialize a text value as a string.
+///
+/// This can be used with serde's `serialize_with` and `with` field attributes.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// extern crate textkind;
+/// extern crate serde_json;
+///
+/// let text = textkind::Title::<String>::try_from_str("foo")?;
+///
+/// let value = textkind::serde_support::serialize(&text, serde_json::value::Serializer)?;
+/// assert_eq!(value, serde_json::Value::String("foo".into()));
+/// # Ok(())
+/// # }
+/// ```
+pub fn serialize<K, D, S>(text: &::Text<K, D>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: ::Kind,
+    D: ::Dynamic,
+    S: serde::Serializer,
+{
+    serializer.serialize_str(text.as_str())
+}
+
+/// Deserialize and validate a text value from a string.
+///
+/// This can be used with serde's `deserialize_with` and `with` field attributes.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// extern crate textkind;
+/// extern crate serde_json;
+///
+/// let value = serde_json::Value::String("foo".into());
+///
+/// let text: textkind::Title<String> = textkind::serde_support::deserialize(value)?;
+/// assert_eq!(text.as_str(), "foo");
+/// # Ok(())
+/// # }
+/// ```
+pub fn deserialize<'de, K, D, De>(deserializer: De) -> Result<::Text<K, D>, De::Error>
+where
+    K: ::Kind,
+    D: ::Dynamic,
+    De: serde::Deserializer<'de>,
+    <<K as ::Kind>::Check as ::Check>::Error: fmt::Display,
+{
+    deserializer.deserialize_str(Visitor {
+        _text: marker::PhantomData,
+    })
+}
+
+/// Deserialize and validate a text value from a string or an integer.
+///
+/// Integers are turned into their decimal string form before validation. This can be used
+/// with serde's `deserialize_with` field attribute.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// extern crate textkind;
+/// extern crate serde_json;
+///
+/// let value = serde_json::Value::from(42);
+///
+/// let text: textkind::IdentifierLax<String> =
+///     textkind::serde_support::deserialize_from_number(value)?;
+/// assert_eq!(text.as_str(), "42");
+/// # Ok(())
+/// # }
+/// ```
+pub fn deserialize_from_number<'de, K, D, De>(deserializer: De) -> Result<::Text<K, D>, De::Error>
+where
+    K: ::Kind,
+    D: ::Dynamic,
+    De: serde::Deserializer<'de>,
+    <<K as ::Kind>::Check as ::Check>::Error: fmt::Display,
+{
+    deserializer.deserialize_any(NumberVisitor {
+        _text: marker::PhantomData,
+    })
+}
@@ -1,7 +1,10 @@
 
 use std::str;
 
-const SMALL_STRING_BUF: usize = 16;
+/// The number of bytes a `SmallString` can store inline.
+///
+/// Constructors like `Text::try_from_str` keep values up to this length in `Data::Small`.
+pub const SMALL_STRING_BUF: usize = 16;
 
 /// Small string data storage.
 ///
@@ -81,6 +81,136 @@ pub trait Check {
     fn check(value: &str) -> Result<(), Self::Error>;
 }
 
+/// Byte length bound trait.
+///
+/// This trait is implemented for `Check` types to communicate the maximum byte length a
+/// valid value can have, if there is one. Combinators like `check::And` will propagate the
+/// bounds of their inner checks.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::BoundedBytes;
+///
+/// type Short = textkind::check::And<
+///     textkind::check::MaxBytes256,
+///     textkind::check::NotEmpty,
+/// >;
+///
+/// assert_eq!(Short::MAX, Some(256));
+/// assert_eq!(textkind::check::NotEmpty::MAX, None);
+/// # Ok(())
+/// # }
+/// ```
+pub trait BoundedBytes {
+
+    /// The maximum number of bytes a valid value can have, or `None` if unbounded.
+    const MAX: Option<usize>;
+}
+
+/// Machine readable error code trait.
+///
+/// This trait is implemented for the errors of the predefined checks. The returned codes are
+/// stable identifiers like `"not_empty"` or `"max_bytes"` that can be used by API layers
+/// without matching on the error types. Combined errors report the code of the inner error
+/// that caused the failure.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use textkind::{Check, CheckErrorCode};
+///
+/// type Short = textkind::check::And<
+///     textkind::check::NotEmpty,
+///     textkind::check::MaxBytes64,
+/// >;
+///
+/// assert_eq!(Short::check("").unwrap_err().code(), "not_empty");
+/// assert_eq!(Short::check(&"x".repeat(65)).unwrap_err().code(), "max_bytes");
+/// # Ok(())
+/// # }
+/// ```
+pub trait CheckErrorCode {
+
+    /// A stable identifier for the kind of failure.
+    fn code(&self) -> &'static str;
+}
+
+/// Context dependent value verification trait.
+///
+/// This is like `Check`, but the check receives additional data at runtime. It can be used
+/// for validations that depend on configuration, like a list of reserved words. Use
+/// `Text::try_from_str_with_context` to run a context check in addition to the check of
+/// the kind.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+/// use std::collections::HashSet;
+///
+/// struct NotReserved;
+///
+/// #[derive(Debug)]
+/// struct ReservedError;
+///
+/// impl textkind::ContextCheck for NotReserved {
+///
+///     type Context = HashSet<&'static str>;
+///     type Error = ReservedError;
+///
+///     fn check_with(value: &str, reserved: &Self::Context) -> Result<(), Self::Error> {
+///         if reserved.contains(value) {
+///             Err(ReservedError)
+///         } else {
+///             Ok(())
+///         }
+///     }
+/// }
+///
+/// let reserved: HashSet<_> = vec!["admin", "root"].into_iter().collect();
+///
+/// let name: textkind::Identifier<String> =
+///     textkind::Identifier::try_from_str_with_context::<NotReserved>("foo", &reserved)
+///         .expect("valid identifier that is not reserved");
+/// assert_eq!(name.as_str(), "foo");
+///
+/// let result: Result<textkind::Identifier<String>, _> =
+///     textkind::Identifier::try_from_str_with_context::<NotReserved>("root", &reserved);
+/// assert!(result.is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub trait ContextCheck {
+
+    /// The additional data passed to the check.
+    type Context: ?Sized;
+
+    /// The error that will be returned when an invalid value is checked.
+    type Error;
+
+    /// Checks the given value for validity with the given context.
+    ///
+    /// # Errors
+    ///
+    /// Returns the specified `Self::Error` if the given value is invalid.
+    fn check_with(value: &str, context: &Self::Context) -> Result<(), Self::Error>;
+}
+
 /// Value identity trait.
 ///
 /// Identifies a kind of text. This provides type safety for different text kinds, but also
@@ -114,11 +244,83 @@ pub trait Kind {
 
     /// A simple description of this kind. This is used in error messages.
     const DESCRIPTION: &'static str;
+
+    /// The maximum number of bytes a valid value of this kind can have.
+    ///
+    /// This is available when the `Check` type implements `BoundedBytes` and returns `None`
+    /// when the check does not impose a byte length limit.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    /// use textkind::Kind;
+    ///
+    /// assert_eq!(textkind::kind::Title::max_bytes(), Some(512));
+    /// ```
+    fn max_bytes() -> Option<usize>
+    where
+        Self::Check: BoundedBytes,
+    {
+        <Self::Check as BoundedBytes>::MAX
+    }
 }
 
+/// Kind implication trait.
+///
+/// Implementing `Implies<K2>` for a kind declares that every value valid for the kind is
+/// also valid for `K2`. This allows infallible transitions between kinds with different
+/// `Check` types via `Text::imply_kind`.
+///
+/// The declaration is not verified. An incorrect implementation allows creating texts of
+/// kind `K2` that contain invalid values.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// extern crate textkind;
+/// # fn main() { example().expect("no errors") }
+/// # fn example() -> Result<(), Box<::std::error::Error>> {
+///
+/// struct Ident;
+/// struct Label;
+///
+/// impl textkind::Kind for Ident {
+///     type Check = textkind::check::IdentifierLax;
+///     const DESCRIPTION: &'static str = "identifier";
+/// }
+///
+/// impl textkind::Kind for Label {
+///     type Check = textkind::check::NotEmpty;
+///     const DESCRIPTION: &'static str = "label";
+/// }
+///
+/// // identifiers are never empty
+/// impl textkind::Implies<Label> for Ident {}
+///
+/// let ident: textkind::Text<Ident, String> = textkind::Text::try_from_str("foo")?;
+/// let label: textkind::Text<Label, String> = ident.imply_kind();
+/// assert_eq!(label.as_str(), "foo");
+/// # Ok(())
+/// # }
+/// ```
+pub trait Implies<K2>: Kind
+where
+    K2: Kind,
+{}
+
 /// Dynamic storage trait.
 ///
 /// This trait is implemented for types that provide dynamic storage for text values.
+///
+/// Storage types do not have to be backed by a `String`. A type like a handle into a string
+/// interner can leave [`try_extract_string`](#method.try_extract_string) at its default of
+/// returning `Err(self)` and signal via [`is_extractable`](#method.is_extractable) that
+/// extraction is never possible.
 pub trait Dynamic: Clone {
 
     /// Construct the dynamic storage from a `&str` slice.
@@ -194,6 +396,32 @@ pub trait Dynamic: Clone {
     /// ```
     fn from_string(value: String) -> Self;
 
+    /// Construct the dynamic storage from a `Box<str>`.
+    ///
+    /// This will delegate to [`from_string`](#method.from_string) by default.
+    ///
+    /// A type should implement this method if it can take over a `Box<str>` as storage.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// use std::sync::Arc;
+    /// use textkind::Dynamic;
+    ///
+    /// let value: Arc<String> = Dynamic::from_boxed_str("foo".into());
+    /// assert_eq!(value.as_str(), "foo");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_boxed_str(value: Box<str>) -> Self {
+        Self::from_string(value.into_string())
+    }
+
     /// Construct the dynamic storage from another dynamic storage.
     ///
     /// The implementing type should use [`as_str`](#method.as_str),
@@ -240,6 +468,54 @@ pub trait Dynamic: Clone {
     /// ```
     fn as_str(&self) -> &str;
 
+    /// Return the number of bytes the storage can hold without reallocating.
+    ///
+    /// This will return the byte length of [`as_str`](#method.as_str) by default.
+    ///
+    /// A type should implement this method if it can hold spare capacity.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// use textkind::Dynamic;
+    ///
+    /// let value = String::with_capacity(64);
+    /// assert!(Dynamic::capacity(&value) >= 64);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn capacity(&self) -> usize { self.as_str().len() }
+
+    /// Release spare capacity held by the storage.
+    ///
+    /// This will return the storage unchanged by default.
+    ///
+    /// A type should implement this method if it can hold spare capacity that can be released.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    /// # fn main() { example().expect("no errors") }
+    /// # fn example() -> Result<(), Box<::std::error::Error>> {
+    /// use textkind::Dynamic;
+    ///
+    /// let mut value = String::with_capacity(64);
+    /// value.push_str("foo");
+    /// let value = Dynamic::shrink(value);
+    /// assert_eq!(value.capacity(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn shrink(self) -> Self { self }
+
     /// Attempt to extract a `String` from the dynamic storage.
     ///
     /// This will signal extraction failure by default.
@@ -272,11 +548,33 @@ pub trait Dynamic: Clone {
     /// ```
     fn try_extract_string(self) -> Result<String, Self> { Err(self) }
 
+    /// Whether the storage type can ever extract a `String` from its values.
+    ///
+    /// This returns `true` by default.
+    ///
+    /// A type should implement this method returning `false` if its
+    /// [`try_extract_string`](#method.try_extract_string) always fails. The default
+    /// [`into_string`](#method.into_string) will then skip the extraction attempt and
+    /// construct a new `String` right away.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate textkind;
+    /// use textkind::Dynamic;
+    ///
+    /// assert!(<String as Dynamic>::is_extractable());
+    /// ```
+    fn is_extractable() -> bool { true }
+
     /// Extract or construct a `String` from the value.
     ///
     /// This will try to extract a `String` via
     /// [`try_extract_string`](#method.try_extract_string) and if not possible construct a
-    /// new `String` via [`as_str`](#method.as_str) by default.
+    /// new `String` via [`as_str`](#method.as_str) by default. The extraction is skipped if
+    /// [`is_extractable`](#method.is_extractable) returns `false`.
     ///
     /// A type may implement this method instead of
     /// [`try_extract_string`](#method.try_extract_string) if there is no way it will be
@@ -300,6 +598,9 @@ pub trait Dynamic: Clone {
     /// # }
     /// ```
     fn into_string(self) -> String {
+        if !Self::is_extractable() {
+            return self.as_str().into();
+        }
         match self.try_extract_string() {
             Ok(value) => value,
             Err(dynamic) => dynamic.as_str().into(),
@@ -314,7 +615,8 @@ impl Dynamic for String {
     fn from_string(value: String) -> Self { value }
 
     /// Use the dynamic storage's `into_string` method to construct a `String` and uses
-    /// that as dynamic storage.
+    /// that as dynamic storage. This reuses the allocation and capacity when the other
+    /// storage can extract its `String`.
     fn from<D>(dynamic: D) -> Self
     where
         D: Dynamic,
@@ -325,6 +627,15 @@ impl Dynamic for String {
     /// Fetch the `&str` slice from the `String`.
     fn as_str(&self) -> &str { self }
 
+    /// Return the capacity of the `String`.
+    fn capacity(&self) -> usize { String::capacity(self) }
+
+    /// Shrink the capacity of the `String` to its length.
+    fn shrink(mut self) -> Self {
+        self.shrink_to_fit();
+        self
+    }
+
     /// Return the dynamic storage.
     fn into_string(self) -> String { self }
 
@@ -349,6 +660,9 @@ impl Dynamic for rc::Rc<String> {
     /// Fetch the `&str` slice from the `std::rc::Rc<String>`.
     fn as_str(&self) -> &str { self }
 
+    /// Return the capacity of the shared `String`.
+    fn capacity(&self) -> usize { String::capacity(self) }
+
     /// Extract the `String` from the `std::rc::Rc<String>` if the current handle
     /// to the shared storage is the only one.
     fn try_extract_string(self) -> Result<String, Self> {
@@ -373,6 +687,9 @@ impl Dynamic for sync::Arc<String> {
     /// Fetch the `&str` slice from the `std::sync::Arc<String>`.
     fn as_str(&self) -> &str { self }
 
+    /// Return the capacity of the shared `String`.
+    fn capacity(&self) -> usize { String::capacity(self) }
+
     /// Extract the `String` from the `std::sync::Arc<String>` if the current handle
     /// to the shared storage is the only one.
     fn try_extract_string(self) -> Result<String, Self> {
@@ -380,3 +697,26 @@ impl Dynamic for sync::Arc<String> {
     }
 }
 
+/// Implementation of `Dynamic` for boxed string slices.
+impl Dynamic for Box<str> {
+
+    /// Turn the `String` into a `Box<str>`.
+    fn from_string(value: String) -> Self { value.into_boxed_str() }
+
+    /// Use the passed `Box<str>` as dynamic storage.
+    fn from_boxed_str(value: Box<str>) -> Self { value }
+
+    /// Turn the other storage's `into_string` result into a `Box<str>`.
+    fn from<D>(dynamic: D) -> Self
+    where
+        D: Dynamic,
+    {
+        dynamic.into_string().into_boxed_str()
+    }
+
+    /// Fetch the `&str` slice from the `Box<str>`.
+    fn as_str(&self) -> &str { self }
+
+    /// Turn the `Box<str>` into a `String` without reallocating.
+    fn try_extract_string(self) -> Result<String, Self> { Ok(self.into_string()) }
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "borsh")]
+
+extern crate textkind;
+extern crate borsh;
+
+#[test]
+fn roundtrip() {
+
+    let text = textkind::Title::<String>::try_from_str("foo").unwrap();
+    let bytes = borsh::to_vec(&text).unwrap();
+    assert_eq!(bytes, borsh::to_vec("foo").unwrap());
+
+    let restored: textkind::Title<String> = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(restored.as_str(), "foo");
+
+    let restored: textkind::Title<::std::sync::Arc<String>> = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(restored.as_str(), "foo");
+}
+
+#[test]
+fn deserialize_rejects_invalid() {
+
+    let bytes = borsh::to_vec("foo bar").unwrap();
+
+    let result: Result<textkind::Identifier<String>, _> = borsh::from_slice(&bytes);
+    let error = result.err().expect("title with whitespace is not an identifier");
+    assert_eq!(error.kind(), ::std::io::ErrorKind::InvalidData);
+    assert!(format!("{}", error).contains("invalid identifier"));
+}
@@ -283,6 +283,10 @@ fn title() {
 #[test]
 fn max_bytes() {
 
+    expect_pass!(MaxBytes64: "foo");
+    expect_pass!(MaxBytes64: "");
+    expect_pass!(MaxBytes64: &"X".repeat(64));
+
     expect_pass!(MaxBytes256: "foo");
     expect_pass!(MaxBytes256: "");
     expect_pass!(MaxBytes256: &"X".repeat(256));
@@ -295,6 +299,11 @@ fn max_bytes() {
     expect_pass!(MaxBytes1024: "");
     expect_pass!(MaxBytes1024: &"X".repeat(1024));
 
+    let error = expect_fail!(MaxBytes64: &"X".repeat(65));
+    assert_display!(error, "length of 65");
+    assert_display!(error, "limit of 64");
+    assert_debug!(error, "MaxBytesError");
+
     let error = expect_fail!(MaxBytes256: &"X".repeat(257));
     assert_display!(error, "length of 257");
     assert_display!(error, "limit of 256");
@@ -311,3 +320,977 @@ fn max_bytes() {
     assert_debug!(error, "MaxBytesError");
 }
 
+#[test]
+fn max_bytes_const_generic() {
+    use textkind::BoundedBytes;
+
+    expect_pass!(MaxBytes<0>: "");
+    expect_pass!(MaxBytes<200>: &"X".repeat(200));
+
+    let error = expect_fail!(MaxBytes<200>: &"X".repeat(201));
+    assert_eq!(error, MaxBytesError { max: 200, len: 201 });
+
+    let error = expect_fail!(MaxBytes<0>: "a");
+    assert_eq!(error, MaxBytesError { max: 0, len: 1 });
+
+    expect_pass!(MaxBytes<10>: "0123456789");
+    expect_pass!(MaxBytes<10>: "käsekäse");
+    let error = expect_fail!(MaxBytes<10>: "käsekäsen");
+    assert_eq!(error, MaxBytesError { max: 10, len: 11 });
+
+    assert_eq!(MaxBytes::<200>::MAX, Some(200));
+    assert_eq!(<MaxBytes64 as BoundedBytes>::MAX, <MaxBytes<64> as BoundedBytes>::MAX);
+}
+
+
+#[test]
+fn min_bytes() {
+    use textkind::BoundedBytes;
+
+    expect_pass!(MinBytes<0>: "");
+    expect_pass!(MinBytes<3>: "foo");
+    expect_pass!(MinBytes<3>: "foobar");
+
+    let error = expect_fail!(MinBytes<3>: "fo");
+    assert_eq!(error, MinBytesError { min: 3, len: 2 });
+    assert_display!(error, "length of 2 is below minimum of 3");
+    assert_debug!(error, "MinBytesError");
+
+    assert_eq!(MinBytes::<3>::MAX, None);
+}
+
+#[test]
+fn byte_range() {
+    use textkind::BoundedBytes;
+
+    expect_pass!(ByteRange<4, 8>: "four");
+    expect_pass!(ByteRange<4, 8>: "eight!!!");
+    expect_pass!(ByteRange<4, 4>: "four");
+
+    let error = expect_fail!(ByteRange<4, 8>: "thr");
+    assert_eq!(error, ByteRangeError { min: 4, max: 8, len: 3 });
+    assert_display!(error, "length of 3 is outside of the range 4 to 8");
+
+    let error = expect_fail!(ByteRange<4, 8>: "nine!!!!!");
+    assert_eq!(error, ByteRangeError { min: 4, max: 8, len: 9 });
+    assert_debug!(error, "ByteRangeError");
+
+    expect_fail!(ByteRange<4, 8>: "");
+
+    assert_eq!(ByteRange::<4, 8>::MAX, Some(8));
+
+    let error: ByteRangeError = MinBytesError { min: 2, len: 1 }.into();
+    assert_eq!(error.min, 2);
+    let error: ByteRangeError = MaxBytesError { max: 2, len: 3 }.into();
+    assert_eq!(error, ByteRangeError { min: 0, max: 2, len: 3 });
+}
+
+
+#[test]
+fn bounded_bytes() {
+    use textkind::{BoundedBytes, Kind};
+
+    assert_eq!(MaxBytes256::MAX, Some(256));
+    assert_eq!(NotEmpty::MAX, None);
+    assert_eq!(WhenTrimmed::<MaxBytes256>::MAX, None);
+
+    assert_eq!(And::<MaxBytes256, MaxBytes512>::MAX, Some(256));
+    assert_eq!(And::<MaxBytes1024, MaxBytes512>::MAX, Some(512));
+    assert_eq!(And::<NotEmpty, MaxBytes512>::MAX, Some(512));
+    assert_eq!(And::<NotEmpty, NoControl>::MAX, None);
+    assert_eq!(And::<Title, And<MaxBytes1024, MaxBytes256>>::MAX, Some(256));
+
+    assert_eq!(Or::<MaxBytes256, MaxBytes512>::MAX, Some(512));
+    assert_eq!(Or::<NotEmpty, MaxBytes512>::MAX, None);
+
+    assert_eq!(textkind::kind::Title::max_bytes(), Some(512));
+    assert_eq!(textkind::kind::Identifier::max_bytes(), Some(512));
+    assert_eq!(textkind::kind::Tag::max_bytes(), Some(64));
+}
+
+#[test]
+fn ascii_alpha() {
+
+    expect_pass!(AsciiAlpha: "foo");
+    expect_pass!(AsciiAlpha: "FooBar");
+    expect_pass!(AsciiAlpha: "");
+
+    let error = expect_fail!(AsciiAlpha: "foo2");
+    assert_eq!(error, AsciiAlphaError { found: '2', index: 3 });
+    assert_display!(error, "`2` at index 3");
+    assert_debug!(error, "AsciiAlphaError");
+
+    let error = expect_fail!(AsciiAlpha: "äb");
+    assert_eq!(error, AsciiAlphaError { found: 'ä', index: 0 });
+    expect_fail!(AsciiAlpha: "foo bar");
+}
+
+#[test]
+fn ascii_digit() {
+
+    expect_pass!(AsciiDigit: "0123456789");
+    expect_pass!(AsciiDigit: "");
+
+    let error = expect_fail!(AsciiDigit: "12a4");
+    assert_eq!(error, AsciiDigitError { found: 'a', index: 2 });
+    assert_display!(error, "`a` at index 2");
+    assert_debug!(error, "AsciiDigitError");
+
+    expect_fail!(AsciiDigit: "-1");
+    expect_fail!(AsciiDigit: "1.0");
+}
+
+#[test]
+fn ascii_alphanumeric() {
+
+    expect_pass!(AsciiAlphanumeric: "foo23");
+    expect_pass!(AsciiAlphanumeric: "23");
+    expect_pass!(AsciiAlphanumeric: "");
+
+    let error = expect_fail!(AsciiAlphanumeric: "ab-12");
+    assert_eq!(error, AsciiAlphanumericError { found: '-', index: 2 });
+    assert_display!(error, "`-` at index 2");
+    assert_debug!(error, "AsciiAlphanumericError");
+
+    expect_fail!(AsciiAlphanumeric: "foo_bar");
+    expect_fail!(AsciiAlphanumeric: "foo\n");
+}
+
+#[test]
+fn ascii() {
+
+    expect_pass!(Ascii: "foo bar\t~!");
+    expect_pass!(Ascii: "");
+    expect_pass!(Ascii: "\x7f");
+
+    let error = expect_fail!(Ascii: "käse");
+    assert_eq!(error, AsciiError { found: 'ä', index: 1 });
+    assert_display!(error, "`\\u{e4}` at index 1 that is not ASCII");
+    assert_debug!(error, "AsciiError");
+}
+
+#[test]
+fn semver_core() {
+
+    expect_pass!(SemverCore: "1.2.3");
+    expect_pass!(SemverCore: "0.0.0");
+    expect_pass!(SemverCore: "10.20.30");
+
+    let error = expect_fail!(SemverCore: "");
+    assert_eq!(error, SemverError::MissingComponent(SemverComponent::Major));
+
+    let error = expect_fail!(SemverCore: "1.2");
+    assert_eq!(error, SemverError::MissingComponent(SemverComponent::Patch));
+    assert_display!(error, "missing the patch version");
+    assert_debug!(error, "MissingComponent");
+
+    let error = expect_fail!(SemverCore: "1..3");
+    assert_eq!(error, SemverError::MissingComponent(SemverComponent::Minor));
+
+    let error = expect_fail!(SemverCore: "1.x.3");
+    assert_eq!(error, SemverError::NonNumericComponent(SemverComponent::Minor));
+    assert_display!(error, "non-numeric minor");
+
+    let error = expect_fail!(SemverCore: "01.2.3");
+    assert_eq!(error, SemverError::LeadingZero(SemverComponent::Major));
+    assert_display!(error, "leading zero");
+
+    let error = expect_fail!(SemverCore: "1.2.3.4");
+    assert_eq!(error, SemverError::ExtraComponent);
+
+    let error = expect_fail!(SemverCore: "1.2.3-rc");
+    assert_eq!(error, SemverError::NonNumericComponent(SemverComponent::Patch));
+}
+
+#[test]
+fn semver() {
+
+    expect_pass!(Semver: "1.2.3");
+    expect_pass!(Semver: "1.2.3-alpha");
+    expect_pass!(Semver: "1.2.3-alpha.1.x-y");
+    expect_pass!(Semver: "1.2.3-0.3.7");
+    expect_pass!(Semver: "1.2.3+build");
+    expect_pass!(Semver: "1.2.3+001");
+    expect_pass!(Semver: "1.0.0-rc.1+build.5");
+
+    let error = expect_fail!(Semver: "1.2");
+    assert_eq!(error, SemverError::MissingComponent(SemverComponent::Patch));
+
+    let error = expect_fail!(Semver: "1.2.3-");
+    assert_eq!(error, SemverError::InvalidPreRelease);
+    assert_display!(error, "pre-release");
+
+    let error = expect_fail!(Semver: "1.2.3-rc..1");
+    assert_eq!(error, SemverError::InvalidPreRelease);
+
+    let error = expect_fail!(Semver: "1.2.3-01");
+    assert_eq!(error, SemverError::InvalidPreRelease);
+
+    let error = expect_fail!(Semver: "1.2.3+");
+    assert_eq!(error, SemverError::InvalidBuild);
+    assert_display!(error, "build metadata");
+
+    let error = expect_fail!(Semver: "1.2.3+build!");
+    assert_eq!(error, SemverError::InvalidBuild);
+
+    let error = expect_fail!(Semver: "1.2.3+build+other");
+    assert_eq!(error, SemverError::InvalidBuild);
+}
+
+#[test]
+fn all_macro() {
+
+    type Combined = all!(NotEmpty, SingleLine, Trimmed, MaxBytes256);
+
+    expect_pass!(Combined: "foo bar");
+
+    let error = expect_fail!(Combined: "");
+    assert_eq!(error, AndError::Err1(NotEmptyError));
+
+    let error = expect_fail!(Combined: "foo\nbar");
+    assert_eq!(error, AndError::Err2(AndError::Err1(SingleLineError)));
+
+    let error = expect_fail!(Combined: " foo");
+    match error {
+        AndError::Err2(AndError::Err2(AndError::Err1(_))) => (),
+        other => panic!("unexpected error {:?}", other),
+    }
+
+    let long = "x".repeat(300);
+    let error = expect_fail!(Combined: &long);
+    match error {
+        AndError::Err2(AndError::Err2(AndError::Err2(_))) => (),
+        other => panic!("unexpected error {:?}", other),
+    }
+
+    type Single = all!(NotEmpty);
+    expect_pass!(Single: "foo");
+}
+
+#[test]
+fn title_case() {
+
+    expect_pass!(TitleCase: "");
+    expect_pass!(TitleCase: "Foo");
+    expect_pass!(TitleCase: "The Lord of the Rings");
+    expect_pass!(TitleCase: "War and Peace");
+    expect_pass!(TitleCase: "Chapter 2: A New (Hope)");
+    expect_pass!(TitleCase: "'Quoted' Words, and Others");
+    expect_pass!(TitleCase: "Ünïcode Wörds");
+
+    let error = expect_fail!(TitleCase: "the Lord of the Rings");
+    assert_eq!(error.word, "the");
+    assert_eq!(error.index, 0);
+    assert_display!(error, "`the` at index 0");
+    assert_debug!(error, "TitleCaseError");
+
+    let error = expect_fail!(TitleCase: "The lord of the rings");
+    assert_eq!(error.word, "lord");
+    assert_eq!(error.index, 4);
+
+    let error = expect_fail!(TitleCase: "Foo  \"bar\"");
+    assert_eq!(error.word, "\"bar\"");
+    assert_eq!(error.index, 5);
+}
+
+title_case_check! {
+    /// Title case accepting only "von" in lowercase.
+    VonTitleCase: ["von"]
+}
+
+#[test]
+fn title_case_custom() {
+
+    expect_pass!(VonTitleCase: "Ludwig von Beethoven");
+
+    let error = expect_fail!(VonTitleCase: "von Beethoven");
+    assert_eq!(error.word, "von");
+
+    let error = expect_fail!(VonTitleCase: "The Lord of the Rings");
+    assert_eq!(error.word, "of");
+}
+
+#[test]
+fn or() {
+
+    type Combined = Or<AsciiDigit, AsciiAlpha>;
+
+    expect_pass!(Combined: "123");
+    expect_pass!(Combined: "abc");
+
+    let error = expect_fail!(Combined: "a1");
+    assert_eq!(error.0.found, 'a');
+    assert_eq!(error.1.found, '1');
+    assert_display!(error, "none of the alternatives");
+    assert_debug!(error, "OrError");
+}
+
+#[test]
+fn any_macro() {
+
+    type Combined = any!(AsciiDigit, AsciiAlpha, Semver);
+
+    expect_pass!(Combined: "1.2.3-rc.1");
+
+    let error = expect_fail!(Combined: "1.2.3-");
+    assert_eq!((error.1).1, SemverError::InvalidPreRelease);
+
+    type Single = any!(NotEmpty);
+    expect_pass!(Single: "foo");
+}
+
+contains_check!(
+    /// Requires an `@`.
+    HasAt, "@"
+);
+
+#[test]
+fn contains() {
+
+    expect_pass!(HasAt: "user@example.com");
+    expect_pass!(HasAt: "@");
+
+    let error = expect_fail!(HasAt: "user");
+    assert_eq!(error, ContainsError { needle: "@" });
+    assert_display!(error, "does not contain `@`");
+    assert_debug!(error, "ContainsError");
+
+    expect_fail!(HasAt: "");
+}
+
+case_insensitive_one_of!(
+    /// Requires a boolean keyword.
+    Bool, "true", "false"
+);
+
+#[test]
+fn case_insensitive_one_of() {
+
+    expect_pass!(Bool: "true");
+    expect_pass!(Bool: "TRUE");
+    expect_pass!(Bool: "True");
+    expect_pass!(Bool: "fAlSe");
+
+    let error = expect_fail!(Bool: "yes");
+    assert_eq!(error, OneOfError { values: &["true", "false"] });
+    assert_display!(error, "not one of `true`, `false`");
+    assert_debug!(error, "OneOfError");
+
+    expect_fail!(Bool: "");
+    expect_fail!(Bool: "truee");
+}
+
+not_one_of!(
+    /// Rejects reserved names.
+    Reserved, "admin", "root", "system"
+);
+
+not_one_of!(
+    /// Rejects reserved names ignoring case.
+    ReservedAnyCase, ignore_ascii_case, "admin", "root",
+);
+
+#[test]
+fn not_one_of() {
+
+    expect_pass!(Reserved: "alice");
+    expect_pass!(Reserved: "Admin");
+    expect_pass!(Reserved: "");
+    expect_pass!(Reserved: "rooted");
+
+    let error = expect_fail!(Reserved: "root");
+    assert_eq!(error, NotOneOfError { matched: "root" });
+    assert_display!(error, "`root` is not allowed");
+    assert_debug!(error, "NotOneOfError");
+
+    expect_pass!(ReservedAnyCase: "alice");
+    expect_pass!(ReservedAnyCase: "system");
+
+    let error = expect_fail!(ReservedAnyCase: "ADMIN");
+    assert_eq!(error, NotOneOfError { matched: "admin" });
+
+    expect_pass!(And<Identifier, Reserved>: "alice");
+    let error = expect_fail!(And<Identifier, Reserved>: "system");
+    assert_eq!(error, AndError::Err2(NotOneOfError { matched: "system" }));
+}
+
+not_equal_check!(
+    /// Rejects path keywords.
+    NotKeyword = ["self", "super", "crate"]
+);
+
+not_equal_check_ci!(
+    /// Rejects path keywords ignoring case.
+    NotKeywordAnyCase = ["self", "super", "crate",]
+);
+
+#[test]
+fn not_equal() {
+
+    expect_pass!(NotKeyword: "module");
+    expect_pass!(NotKeyword: "Self");
+    expect_pass!(NotKeyword: "selfish");
+
+    let error: ReservedWordError = expect_fail!(NotKeyword: "super");
+    assert_eq!(error.matched, "super");
+    assert_display!(error, "`super` is not allowed");
+
+    expect_pass!(NotKeywordAnyCase: "module");
+    let error = expect_fail!(NotKeywordAnyCase: "CRATE");
+    assert_eq!(error, ReservedWordError { matched: "crate" });
+
+    expect_pass!(And<Identifier, NotKeyword>: "module");
+    let error = expect_fail!(And<Identifier, NotKeyword>: "self");
+    assert_eq!(error, AndError::Err2(ReservedWordError { matched: "self" }));
+}
+
+allowed_chars_check!(
+    /// Allows lowercase letters, digits, `_` and `.`.
+    ModulePath = ['a'..='z', '0'..='9', '_', '.']
+);
+
+#[test]
+fn allowed_chars() {
+
+    expect_pass!(ModulePath: "");
+    expect_pass!(ModulePath: "std.fmt_2");
+    expect_pass!(ModulePath: "_.._");
+
+    let error = expect_fail!(ModulePath: "std::fmt");
+    assert_eq!(error, AllowedCharsError { found: ':', index: 3 });
+    assert_display!(error, "`:` at index 3");
+    assert_debug!(error, "AllowedCharsError");
+
+    let error = expect_fail!(ModulePath: "aä");
+    assert_eq!(error, AllowedCharsError { found: 'ä', index: 1 });
+
+    let error = expect_fail!(ModulePath: "Std");
+    assert_eq!(error.index, 0);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FieldError {
+    Name(IdentifierError),
+    Value(AndError<NotEmptyError, NoControlError>),
+    BoundedName(AndError<MaxBytesError, IdentifierError>),
+}
+
+impl From<IdentifierError> for FieldError {
+
+    fn from(error: IdentifierError) -> Self { FieldError::Name(error) }
+}
+
+impl From<AndError<NotEmptyError, NoControlError>> for FieldError {
+
+    fn from(error: AndError<NotEmptyError, NoControlError>) -> Self { FieldError::Value(error) }
+}
+
+impl From<AndError<MaxBytesError, IdentifierError>> for FieldError {
+
+    fn from(error: AndError<MaxBytesError, IdentifierError>) -> Self {
+        FieldError::BoundedName(error)
+    }
+}
+
+map_err_check!(
+    /// Requires a field name.
+    FieldName = Identifier => FieldError
+);
+
+map_err_check!(
+    /// Requires a field value.
+    FieldValue = And<NotEmpty, NoControl> => FieldError
+);
+
+map_err_check!(
+    /// Requires a field name of at most 256 bytes.
+    BoundedFieldName = And<MaxBytes256, Identifier> => FieldError
+);
+
+#[test]
+fn map_err() {
+
+    expect_pass!(FieldName: "foo");
+    expect_pass!(FieldValue: "foo bar");
+
+    let error = expect_fail!(FieldName: "2foo");
+    assert_eq!(error, FieldError::Name(IdentifierError::InvalidStartChar('2')));
+
+    let error = expect_fail!(FieldValue: "");
+    assert_debug!(error, "Value(Err1(NotEmptyError))");
+}
+
+#[test]
+fn map_err_bounded_bytes() {
+    use textkind::BoundedBytes;
+
+    assert_eq!(<BoundedFieldName as BoundedBytes>::MAX, Some(256));
+    assert_eq!(<FieldName as BoundedBytes>::MAX, None);
+
+    let error = expect_fail!(BoundedFieldName: &"x".repeat(257));
+    assert_debug!(error, "BoundedName(Err1(MaxBytesError");
+}
+
+line_length_check!(
+    /// Limits lines to 4 bytes.
+    Wrap4, 4
+);
+
+#[test]
+fn line_length() {
+
+    expect_pass!(Wrap4: "");
+    expect_pass!(Wrap4: "1234");
+    expect_pass!(Wrap4: "1234\n1234\n");
+    expect_pass!(Wrap4: "\n\n\n");
+
+    let error = expect_fail!(Wrap4: "1234\n12345\n123456");
+    assert_eq!(error, LineLengthError { max: 4, line_index: 1, len: 5 });
+    assert_display!(error, "line 1 with 5 bytes");
+    assert_debug!(error, "LineLengthError");
+
+    let error = expect_fail!(Wrap4: "1234\r\n");
+    assert_eq!(error, LineLengthError { max: 4, line_index: 0, len: 5 });
+
+    let error = expect_fail!(Wrap4: "äöü");
+    assert_eq!(error.len, 6);
+}
+
+#[test]
+fn no_bidi_control() {
+
+    expect_pass!(NoBidiControl: "");
+    expect_pass!(NoBidiControl: "foo bar");
+    expect_pass!(NoBidiControl: "\u{2029}\u{202F}\u{2065}\u{206A}");
+
+    let error = expect_fail!(NoBidiControl: "ab\u{202E}cd\u{2066}");
+    assert_eq!(error, BidiControlError { found: '\u{202E}', index: 2 });
+    assert_display!(error, "`\\u{202e}` at index 2");
+    assert_debug!(error, "BidiControlError");
+
+    let controls = ['\u{202A}', '\u{202B}', '\u{202C}', '\u{202D}', '\u{2067}', '\u{2068}'];
+    for &c in &controls {
+        expect_fail!(NoBidiControl: &c.to_string());
+    }
+}
+
+#[test]
+fn env_var_name() {
+
+    expect_pass!(EnvVarName: "PATH");
+    expect_pass!(EnvVarName: "MY_VAR2");
+    expect_pass!(EnvVarName: "_private");
+    expect_pass!(EnvVarName: "lower");
+
+    let error = expect_fail!(EnvVarName: "");
+    assert_debug!(error, "NotEmptyError");
+
+    let error = expect_fail!(EnvVarName: "2VAR");
+    assert_eq!(error, EnvVarNameError::DigitStart('2'));
+    assert_display!(error, "starts with digit `2`");
+
+    let error = expect_fail!(EnvVarName: "my-var");
+    assert_eq!(error, EnvVarNameError::InvalidChar { found: '-', index: 2 });
+    assert_display!(error, "`-` at index 2");
+    assert_debug!(error, "InvalidChar");
+
+    let error = expect_fail!(EnvVarName: "MY VAR");
+    assert_eq!(error, EnvVarNameError::InvalidChar { found: ' ', index: 2 });
+}
+
+#[test]
+fn env_var_name_upper() {
+
+    expect_pass!(EnvVarNameUpper: "PATH");
+    expect_pass!(EnvVarNameUpper: "MY_VAR2");
+
+    let error = expect_fail!(EnvVarNameUpper: "MY_Var");
+    assert_eq!(error, EnvVarNameError::LowercaseChar { found: 'a', index: 4 });
+    assert_display!(error, "lowercase character `a` at index 4");
+
+    let error = expect_fail!(EnvVarNameUpper: "2VAR");
+    assert_eq!(error, EnvVarNameError::DigitStart('2'));
+}
+
+#[test]
+fn ascii_printable() {
+
+    expect_pass!(AsciiPrintable: "");
+    expect_pass!(AsciiPrintable: " ");
+    expect_pass!(AsciiPrintable: "Foo Bar ~23!");
+
+    let error = expect_fail!(AsciiPrintable: "foo\tbar");
+    assert_eq!(error, AsciiPrintableError { byte: b'\t', index: 3 });
+    assert_display!(error, "byte 0x09 at index 3");
+    assert_debug!(error, "AsciiPrintableError");
+
+    let error = expect_fail!(AsciiPrintable: "\n");
+    assert_eq!(error.byte, b'\n');
+
+    let error = expect_fail!(AsciiPrintable: "foo\x7f");
+    assert_eq!(error, AsciiPrintableError { byte: 0x7f, index: 3 });
+
+    let error = expect_fail!(AsciiPrintable: "Kä");
+    assert_eq!(error, AsciiPrintableError { byte: 0xc3, index: 1 });
+}
+
+#[test]
+fn tag() {
+
+    expect_pass!(Tag: "rust-lang");
+    expect_pass!(Tag: "x");
+    expect_pass!(Tag: "2018-edition");
+    expect_pass!(Tag: "a--b");
+
+    let error = expect_fail!(Tag: "");
+    assert_debug!(error, "NotEmptyError");
+
+    let error = expect_fail!(Tag: "Rust");
+    assert_eq!(error, TagError::InvalidChar { found: 'R', index: 0 });
+    assert_display!(error, "`R` at index 0");
+
+    let error = expect_fail!(Tag: "rust.lang");
+    assert_eq!(error, TagError::InvalidChar { found: '.', index: 4 });
+
+    let error = expect_fail!(Tag: "-x");
+    assert_eq!(error, TagError::LeadingHyphen);
+    assert_display!(error, "starts with a hyphen");
+
+    let error = expect_fail!(Tag: "x-");
+    assert_eq!(error, TagError::TrailingHyphen);
+    assert_display!(error, "ends with a hyphen");
+    assert_debug!(error, "TrailingHyphen");
+
+    expect_fail!(Tag: "-");
+}
+
+line_length_check!(
+    /// Limits lines to 8 bytes.
+    Wrap8 = 8
+);
+
+#[test]
+fn line_length_assign_syntax() {
+
+    expect_pass!(Wrap8: "");
+    expect_pass!(Wrap8: "12345678");
+    expect_pass!(Wrap8: "12345678\n12345678");
+
+    let error = expect_fail!(Wrap8: "12345678\n123456789");
+    assert_eq!(error, LineLengthError { max: 8, line_index: 1, len: 9 });
+}
+
+#[test]
+fn json_pointer() {
+
+    expect_pass!(JsonPointer: "");
+    expect_pass!(JsonPointer: "/");
+    expect_pass!(JsonPointer: "/foo/0");
+    expect_pass!(JsonPointer: "/a~1b");
+    expect_pass!(JsonPointer: "/m~0n/~01");
+
+    let error = expect_fail!(JsonPointer: "foo");
+    assert_eq!(error, JsonPointerError::MissingLeadingSlash);
+    assert_display!(error, "start with a slash");
+
+    let error = expect_fail!(JsonPointer: "/a~2");
+    assert_eq!(error, JsonPointerError::InvalidEscape { token: 0, index: 2 });
+    assert_display!(error, "token 0 contains an invalid escape at index 2");
+
+    let error = expect_fail!(JsonPointer: "/foo/bar~");
+    assert_eq!(error, JsonPointerError::InvalidEscape { token: 1, index: 8 });
+}
+
+#[test]
+fn no_nul() {
+
+    expect_pass!(NoNul: "");
+    expect_pass!(NoNul: "a\tb");
+
+    let error = expect_fail!(NoNul: "a\0b");
+    assert_eq!(error, NulError { index: 1 });
+    assert_display!(error, "NUL character at index 1");
+
+    let error = expect_fail!(NoNul: "\0");
+    assert_eq!(error.index, 0);
+}
+
+#[test]
+fn decimal() {
+
+    expect_pass!(Decimal: "0");
+    expect_pass!(Decimal: "-0");
+    expect_pass!(Decimal: "0.5");
+    expect_pass!(Decimal: "-3.14");
+    expect_pass!(Decimal: "100.00");
+    expect_pass!(Decimal: "42");
+
+    let error = expect_fail!(Decimal: "01");
+    assert_eq!(error, DecimalError::LeadingZero);
+    assert_display!(error, "leading zero");
+
+    assert_eq!(expect_fail!(Decimal: "."), DecimalError::MissingIntegerDigits);
+    assert_eq!(expect_fail!(Decimal: ""), DecimalError::MissingIntegerDigits);
+    assert_eq!(expect_fail!(Decimal: "-"), DecimalError::MissingIntegerDigits);
+    assert_eq!(expect_fail!(Decimal: ".5"), DecimalError::MissingIntegerDigits);
+    assert_eq!(expect_fail!(Decimal: "1."), DecimalError::MissingFractionDigits);
+    assert_eq!(expect_fail!(Decimal: "1.2.3"), DecimalError::MultiplePoints);
+
+    let error = expect_fail!(Decimal: "-1.2e3");
+    assert_eq!(error, DecimalError::InvalidChar { found: 'e', index: 4 });
+    assert_display!(error, "`e` at index 4");
+
+    let error = expect_fail!(Decimal: "+1");
+    assert_eq!(error, DecimalError::InvalidChar { found: '+', index: 0 });
+}
+
+decimal_check!(
+    /// Allows two fractional digits.
+    Cents, 2
+);
+
+#[test]
+fn decimal_max_fraction() {
+
+    expect_pass!(Cents: "19.99");
+    expect_pass!(Cents: "-0.5");
+    expect_pass!(Cents: "19");
+
+    let error = expect_fail!(Cents: "19.999");
+    assert_eq!(error, DecimalError::TooManyFractionDigits { max: 2, len: 3 });
+    assert_display!(error, "3 fractional digits, at most 2");
+
+    assert_eq!(expect_fail!(Cents: "01.5"), DecimalError::LeadingZero);
+}
+
+#[test]
+fn tz_name() {
+
+    expect_pass!(TzName: "UTC");
+    expect_pass!(TzName: "America/New_York");
+    expect_pass!(TzName: "America/Argentina/Buenos_Aires");
+    expect_pass!(TzName: "Etc/GMT-14");
+    expect_pass!(TzName: "Fake/Zone");
+
+    let error = expect_fail!(TzName: "America//NY");
+    assert_eq!(error, TzNameError::EmptyComponent { component: 1 });
+    assert_display!(error, "component 1 of the value is empty");
+
+    assert_eq!(expect_fail!(TzName: ""), TzNameError::EmptyComponent { component: 0 });
+    assert_eq!(expect_fail!(TzName: "/UTC"), TzNameError::EmptyComponent { component: 0 });
+    assert_eq!(expect_fail!(TzName: "UTC/"), TzNameError::EmptyComponent { component: 1 });
+
+    let error = expect_fail!(TzName: "Europe/New York");
+    assert_eq!(error, TzNameError::InvalidChar { found: ' ', index: 10 });
+    assert_display!(error, "invalid character ` ` at index 10");
+    assert_debug!(error, "InvalidChar");
+}
+
+#[test]
+fn codes() {
+    use textkind::CheckErrorCode;
+
+    assert_eq!(expect_fail!(NotEmpty: "").code(), "not_empty");
+    assert_eq!(expect_fail!(NoControl: "a\nb").code(), "no_control");
+    assert_eq!(expect_fail!(MaxBytes64: &"x".repeat(65)).code(), "max_bytes");
+    assert_eq!(expect_fail!(MinBytes<2>: "x").code(), "min_bytes");
+    assert_eq!(expect_fail!(Identifier: "").code(), "not_empty");
+    assert_eq!(expect_fail!(Identifier: "-foo").code(), "identifier_start_char");
+    assert_eq!(expect_fail!(Identifier: "foo-bar").code(), "identifier_char");
+    assert_eq!(expect_fail!(AsciiDigit: "12a").code(), "ascii_digit");
+    assert_eq!(expect_fail!(Decimal: "1.2.3").code(), "decimal_multiple_points");
+    assert_eq!(expect_fail!(TzName: "UTC/").code(), "tz_name_empty_component");
+    assert_eq!(expect_fail!(And<NotEmpty, NoControl>: "").code(), "not_empty");
+    assert_eq!(expect_fail!(And<NotEmpty, NoControl>: "\n").code(), "no_control");
+    assert_eq!(expect_fail!(WhenTrimmed<NotEmpty>: "  ").code(), "not_empty");
+}
+
+exact_chars_check!(
+    /// Requires exactly 4 characters.
+    ExactlyFour, 4
+);
+
+#[test]
+fn exact_chars() {
+
+    expect_pass!(ExactlyFour: "ABCD");
+    expect_pass!(ExactlyFour: "äöüß");
+    expect_pass!(ExactlyFour: "a\u{1F600}b ");
+
+    let error = expect_fail!(ExactlyFour: "äöü");
+    assert_eq!(error, ExactCharsError { expected: 4, count: 3 });
+    assert_display!(error, "value has 3 characters instead of 4");
+    assert_debug!(error, "ExactCharsError");
+
+    assert_eq!(expect_fail!(ExactlyFour: "").count, 0);
+    assert_eq!(expect_fail!(ExactlyFour: "ABCDE").count, 5);
+}
+
+#[test]
+fn whitespace_normalized() {
+
+    expect_pass!(WhitespaceNormalized: "");
+    expect_pass!(WhitespaceNormalized: "foo");
+    expect_pass!(WhitespaceNormalized: "foo bar");
+    expect_pass!(WhitespaceNormalized: "ä b c");
+
+    let error = expect_fail!(WhitespaceNormalized: "foo  bar");
+    assert_eq!(error, WhitespaceNormalizedError::Repeated { index: 4 });
+    assert_display!(error, "repeated whitespace at index 4");
+    assert_debug!(error, "Repeated");
+
+    let error = expect_fail!(WhitespaceNormalized: " foo");
+    assert_eq!(error, WhitespaceNormalizedError::Leading);
+    assert_display!(error, "starts with whitespace");
+
+    let error = expect_fail!(WhitespaceNormalized: "foo\n");
+    assert_eq!(error, WhitespaceNormalizedError::Trailing);
+    assert_display!(error, "ends with whitespace");
+
+    let error = expect_fail!(WhitespaceNormalized: "foo\tbar");
+    assert_eq!(error, WhitespaceNormalizedError::NonSpace { found: '\t', index: 3 });
+    assert_display!(error, "whitespace `\\t` at index 3");
+
+    assert_eq!(
+        expect_fail!(WhitespaceNormalized: "a \u{a0}b"),
+        WhitespaceNormalizedError::Repeated { index: 2 },
+    );
+}
+
+#[test]
+fn starts_with_uppercase() {
+
+    expect_pass!(StartsWithUppercase: "Hello");
+    expect_pass!(StartsWithUppercase: "Ärger");
+    expect_pass!(StartsWithUppercase: "  Hello");
+
+    let error = expect_fail!(StartsWithUppercase: "hello");
+    assert_eq!(error, StartsWithUppercaseError { found: Some('h') });
+    assert_display!(error, "starts with `h` instead of an uppercase character");
+    assert_debug!(error, "StartsWithUppercaseError");
+
+    let error = expect_fail!(StartsWithUppercase: "");
+    assert_eq!(error, StartsWithUppercaseError { found: None });
+    assert_display!(error, "does not start with an uppercase character");
+
+    assert_eq!(expect_fail!(StartsWithUppercase: " \t").found, None);
+    assert_eq!(expect_fail!(StartsWithUppercase: "1st").found, Some('1'));
+
+    expect_pass!(And<Title, StartsWithUppercase>: "Hello World");
+    expect_fail!(And<Title, StartsWithUppercase>: " Hello");
+}
+
+#[test]
+fn no_duplicate_char() {
+
+    expect_pass!(NoDuplicateChar: "");
+    expect_pass!(NoDuplicateChar: "abcd");
+    expect_pass!(NoDuplicateChar: "aAäÄ");
+
+    let error = expect_fail!(NoDuplicateChar: "abca");
+    assert_eq!(error, DuplicateCharError { found: 'a', index: 3 });
+    assert_display!(error, "repeats character `a` at index 3");
+    assert_debug!(error, "DuplicateCharError");
+
+    assert_eq!(
+        expect_fail!(NoDuplicateChar: "äbcbä"),
+        DuplicateCharError { found: 'b', index: 4 },
+    );
+
+    expect_pass!(And<Ascii, NoDuplicateChar>: "WXYZ");
+    expect_fail!(And<Ascii, NoDuplicateChar>: "WXYW");
+}
+
+#[test]
+fn nc_name() {
+
+    expect_pass!(NcName: "a");
+    expect_pass!(NcName: "_");
+    expect_pass!(NcName: "item");
+    expect_pass!(NcName: "Item_2.old-name");
+
+    let error = expect_fail!(NcName: "");
+    assert_eq!(error, NcNameError::Empty(NotEmptyError));
+    assert_display!(error, "empty");
+
+    let error = expect_fail!(NcName: "2item");
+    assert_eq!(error, NcNameError::InvalidStartChar { found: '2' });
+    assert_display!(error, "begins with invalid character `2`");
+    assert_debug!(error, "InvalidStartChar");
+
+    assert_eq!(expect_fail!(NcName: ".item"), NcNameError::InvalidStartChar { found: '.' });
+    assert_eq!(expect_fail!(NcName: "-item"), NcNameError::InvalidStartChar { found: '-' });
+    assert_eq!(expect_fail!(NcName: "äb"), NcNameError::InvalidStartChar { found: 'ä' });
+
+    let error = expect_fail!(NcName: "xs:item");
+    assert_eq!(error, NcNameError::InvalidRestChar { found: ':', index: 2 });
+    assert_display!(error, "invalid character `:` at index 2");
+    assert_debug!(error, "InvalidRestChar");
+
+    assert_eq!(
+        expect_fail!(NcName: "käse"),
+        NcNameError::InvalidRestChar { found: 'ä', index: 1 },
+    );
+}
+
+char_set!(
+    /// Allows hexadecimal digits and `-`.
+    HexDash, '0'..='9', 'a'..='f', 'A'..='F', '-'
+);
+
+#[test]
+fn char_set() {
+
+    expect_pass!(HexDash: "");
+    expect_pass!(HexDash: "dead-BEEF-0123");
+    expect_pass!(HexDash: "-");
+
+    let error: CharSetError = expect_fail!(HexDash: "cafe-babe-g");
+    assert_eq!(error, AllowedCharsError { found: 'g', index: 10 });
+    assert_display!(error, "disallowed character `g` at index 10");
+
+    assert_eq!(expect_fail!(HexDash: "ä").index, 0);
+    assert_eq!(expect_fail!(HexDash: "a_b").found, '_');
+}
+
+occurrence_check!(
+    /// Requires exactly one `@`.
+    ExactlyOneAt, '@', 1
+);
+
+occurrence_check!(
+    /// Allows at most two `ä`.
+    AtMostTwoUmlauts, 'ä', at_most 2
+);
+
+occurrence_check!(
+    /// Requires at least two `/`.
+    AtLeastTwoSlashes, '/', at_least 2
+);
+
+#[test]
+fn occurrence() {
+
+    expect_pass!(ExactlyOneAt: "user@example.com");
+    expect_pass!(ExactlyOneAt: "@");
+
+    let error = expect_fail!(ExactlyOneAt: "user");
+    assert_eq!(error, OccurrenceError { found: '@', count: 0, min: 1, max: Some(1) });
+    assert_display!(error, "contains `@` 0 time(s) instead of exactly 1");
+    assert_debug!(error, "OccurrenceError");
+
+    let error = expect_fail!(ExactlyOneAt: "a@b@c");
+    assert_eq!(error.count, 2);
+    assert_display!(error, "contains `@` 2 time(s) instead of exactly 1");
+
+    expect_pass!(AtMostTwoUmlauts: "");
+    expect_pass!(AtMostTwoUmlauts: "äbä");
+    let error = expect_fail!(AtMostTwoUmlauts: "äää");
+    assert_eq!(error, OccurrenceError { found: 'ä', count: 3, min: 0, max: Some(2) });
+    assert_display!(error, "instead of at most 2");
+
+    expect_pass!(AtLeastTwoSlashes: "a/b/c/d");
+    let error = expect_fail!(AtLeastTwoSlashes: "a/b");
+    assert_eq!(error, OccurrenceError { found: '/', count: 1, min: 2, max: None });
+    assert_display!(error, "instead of at least 2");
+}
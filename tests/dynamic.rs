@@ -0,0 +1,160 @@
+
+extern crate textkind;
+
+use textkind::*;
+
+#[derive(Debug, Clone)]
+struct Fixed(String);
+
+impl Dynamic for Fixed {
+
+    fn from_string(value: String) -> Self { Fixed(value) }
+
+    fn from<D>(dynamic: D) -> Self
+    where
+        D: Dynamic,
+    {
+        Fixed(dynamic.into_string())
+    }
+
+    fn as_str(&self) -> &str { &self.0 }
+}
+
+// Mock interner handle that can never give up a `String`.
+#[derive(Debug, Clone, Copy)]
+struct Interned(&'static str);
+
+impl Interned {
+
+    fn intern(value: &str) -> Interned {
+        use std::cell::RefCell;
+        use std::collections::HashSet;
+
+        thread_local! {
+            static POOL: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+        }
+
+        POOL.with(|pool| {
+            let mut pool = pool.borrow_mut();
+            if let Some(&interned) = pool.get(value) {
+                return Interned(interned);
+            }
+            let interned: &'static str = Box::leak(value.to_string().into_boxed_str());
+            pool.insert(interned);
+            Interned(interned)
+        })
+    }
+}
+
+impl Dynamic for Interned {
+
+    fn from_str(value: &str) -> Self { Interned::intern(value) }
+
+    fn from_string(value: String) -> Self { Interned::intern(&value) }
+
+    fn from<D>(dynamic: D) -> Self
+    where
+        D: Dynamic,
+    {
+        Interned::intern(dynamic.as_str())
+    }
+
+    fn as_str(&self) -> &str { self.0 }
+
+    fn try_extract_string(self) -> Result<String, Self> {
+        panic!("extraction should not be attempted")
+    }
+
+    fn is_extractable() -> bool { false }
+}
+
+#[test]
+fn capacity() {
+
+    let mut string = String::with_capacity(64);
+    string.push_str("foo");
+    assert_eq!(Dynamic::capacity(&string), string.capacity());
+
+    let rc = ::std::rc::Rc::new(string.clone());
+    assert_eq!(Dynamic::capacity(&rc), rc.capacity());
+
+    let arc = ::std::sync::Arc::new(string.clone());
+    assert_eq!(Dynamic::capacity(&arc), arc.capacity());
+
+    let fixed = Fixed(string);
+    assert_eq!(fixed.capacity(), 3);
+}
+
+#[test]
+fn shrink() {
+
+    let mut string = String::with_capacity(64);
+    string.push_str("foo");
+    let string = Dynamic::shrink(string);
+    assert_eq!(string.capacity(), 3);
+
+    let arc = ::std::sync::Arc::new(String::with_capacity(64));
+    let ptr = &*arc as *const String;
+    let arc = Dynamic::shrink(arc);
+    assert_eq!(&*arc as *const String, ptr);
+    assert!(arc.capacity() >= 64);
+}
+
+#[test]
+fn from_boxed_str() {
+
+    let fixed: Fixed = Dynamic::from_boxed_str("foo".into());
+    assert_eq!(fixed.as_str(), "foo");
+
+    let boxed: Box<str> = "foo".into();
+    let ptr = boxed.as_ptr();
+    let reused: Box<str> = Dynamic::from_boxed_str(boxed);
+    assert_eq!(reused.as_ptr(), ptr);
+
+    let string: String = Dynamic::from_boxed_str(reused);
+    assert_eq!(string.as_ptr(), ptr);
+}
+
+#[test]
+fn not_extractable() {
+
+    assert!(<String as Dynamic>::is_extractable());
+    assert!(<Fixed as Dynamic>::is_extractable());
+    assert!(!<Interned as Dynamic>::is_extractable());
+
+    let long = "x".repeat(64);
+    let first = Title::<Interned>::try_from_str(&long).unwrap();
+    let second = Title::<Interned>::try_from_string(long.clone()).unwrap();
+    assert_eq!(first.clone().into_dynamic().0.as_ptr(), second.clone().into_dynamic().0.as_ptr());
+
+    assert_eq!(first.clone().into_string(), long);
+    let converted: Title<String> = second.storage_transition();
+    assert_eq!(converted.as_str(), long);
+}
+
+#[test]
+fn from_reuses_extracted_string() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let mut string = String::with_capacity(64);
+    string.push_str("foo");
+    let ptr = string.as_ptr();
+
+    let arc = Arc::new(string);
+    let string: String = Dynamic::from(arc);
+    assert_eq!(string.as_ptr(), ptr);
+    assert_eq!(string.capacity(), 64);
+
+    let rc = Rc::new(string);
+    let string: String = Dynamic::from(rc);
+    assert_eq!(string.as_ptr(), ptr);
+    assert_eq!(string.capacity(), 64);
+
+    let arc = Arc::new(string);
+    let shared = arc.clone();
+    let string: String = Dynamic::from(arc);
+    assert_ne!(string.as_ptr(), ptr);
+    assert_eq!(string, "foo");
+    assert_eq!(shared.as_ptr(), ptr);
+}
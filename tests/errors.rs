@@ -71,3 +71,59 @@ fn error() {
     assert!(format!("{}", with_value.cause().expect("check in cause")).contains("control"));
 }
 
+
+#[test]
+fn explain() {
+
+    let error = Title::<String>::try_from_str("a\nb")
+        .err()
+        .expect("invalid value");
+    assert_eq!(&error.explain(), "invalid title: value contains 1 control character(s)");
+}
+
+#[test]
+fn display_with_value() {
+
+    let error = Title::<String>::try_from_str("a\nb")
+        .err()
+        .expect("invalid value");
+    assert_eq!(
+        error.display_with_value("a\nb").to_string(),
+        "invalid title: `a\\nb` (value contains 1 control character(s))",
+    );
+
+    let long = format!("{}\n", "ä".repeat(70));
+    let error = Title::<String>::try_from_str(&long)
+        .err()
+        .expect("invalid value");
+    assert_eq!(
+        error.display_with_value(&long).to_string(),
+        format!("invalid title: `{}\u{2026}` (value contains 1 control character(s))", "ä".repeat(64)),
+    );
+
+    let exact = format!("-{}", "x".repeat(63));
+    let error = Identifier::<String>::try_from_str(&exact)
+        .err()
+        .expect("invalid identifier start");
+    assert!(error.display_with_value(&exact).to_string().contains(&format!("`{}`", exact)));
+}
+
+#[test]
+fn convert_error() {
+
+    let invalid = || Identifier::<String>::try_from_str("")
+        .err()
+        .expect("invalid value");
+
+    let text = Title::<String>::try_from_str("foo").unwrap();
+    let error = ConvertError("custom", text);
+    assert_eq!(error.value().as_str(), "foo");
+
+    let (error, text) = error.map_error(|_| invalid()).split();
+    assert!(format!("{:?}", error).contains("NotEmptyError"));
+    assert_eq!(text.as_str(), "foo");
+
+    let error = ConvertError("custom", text).map_error(|_| invalid());
+    let with_value: ErrorWithValue<kind::Identifier, Title<String>> = error.into();
+    assert_eq!(with_value.value().as_str(), "foo");
+}
@@ -78,3 +78,83 @@ fn identifier_lax() {
     assert_err!(IdentifierLax: "foo\nbar");
 }
 
+
+#[test]
+fn semver() {
+
+    assert_ok!(Semver: "1.2.3");
+    assert_ok!(Semver: "1.0.0-rc.1+build.5");
+
+    assert_err!(Semver: "");
+    assert_err!(Semver: "1.2");
+    assert_err!(Semver: "v1.2.3");
+    assert_err!(Semver: &format!("1.2.3-{}", "x".repeat(256)));
+}
+
+#[test]
+fn version() {
+
+    assert_ok!(Version: "1.2.3");
+    assert_ok!(Version: "1.0.0-rc.1+build.5");
+
+    assert_err!(Version: "1.2");
+    assert_err!(Version: "01.2.3");
+
+    let version: textkind::Version<String> = textkind::Semver::try_from_str("1.2.3").unwrap();
+    assert_eq!(version, "1.2.3");
+}
+
+#[test]
+fn env_var_name() {
+
+    assert_ok!(EnvVarName: "PATH");
+    assert_ok!(EnvVarName: "MY_VAR2");
+
+    assert_err!(EnvVarName: "");
+    assert_err!(EnvVarName: "2VAR");
+    assert_err!(EnvVarName: "my-var");
+    assert_err!(EnvVarName: &"X".repeat(257));
+}
+
+#[test]
+fn tag() {
+
+    assert_ok!(Tag: "rust-lang");
+    assert_ok!(Tag: &"x".repeat(64));
+
+    assert_err!(Tag: "Rust");
+    assert_err!(Tag: "-x");
+    assert_err!(Tag: &"x".repeat(65));
+}
+
+#[test]
+fn ascii_text() {
+
+    assert_ok!(AsciiText: "foo bar");
+    assert_ok!(AsciiText: &"x".repeat(512));
+
+    assert_err!(AsciiText: "");
+    assert_err!(AsciiText: "käse");
+    assert_err!(AsciiText: &"x".repeat(513));
+}
+
+#[test]
+fn tz_name() {
+
+    assert_ok!(TzName: "Europe/Berlin");
+    assert_ok!(TzName: "UTC");
+
+    assert_err!(TzName: "America//NY");
+    assert_err!(TzName: &"x".repeat(65));
+}
+
+#[test]
+fn nc_name() {
+
+    assert_ok!(NcName: "item");
+    assert_ok!(NcName: "_item-2.old");
+
+    assert_err!(NcName: "");
+    assert_err!(NcName: "xs:item");
+    assert_err!(NcName: &"x".repeat(257));
+}
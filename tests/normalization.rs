@@ -0,0 +1,22 @@
+#![cfg(feature = "unicode-normalization")]
+
+extern crate textkind;
+
+use textkind::*;
+
+#[test]
+fn eq_normalized() {
+    use std::rc::Rc;
+
+    let composed = Title::<String>::try_from_str("Caf\u{e9}").unwrap();
+    let decomposed = Title::<Rc<String>>::try_from_str("cafe\u{301}").unwrap();
+    assert!(composed != decomposed);
+    assert!(composed.eq_normalized(&decomposed));
+    assert!(decomposed.eq_normalized(&composed));
+
+    let upper = Title::<String>::try_from_str("CAF\u{c9}").unwrap();
+    assert!(upper.eq_normalized(&decomposed));
+
+    let other = Title::<String>::try_from_str("Cafe").unwrap();
+    assert!(!other.eq_normalized(&composed));
+}
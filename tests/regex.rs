@@ -0,0 +1,49 @@
+#![cfg(feature = "regex")]
+
+extern crate textkind;
+
+use textkind::Check;
+use textkind::check::*;
+
+regex_any!(
+    /// Accepts a few number formats.
+    Number, r"^0x[0-9a-f]+$", r"^0b[01]+$", r"^[0-9]+$"
+);
+
+#[test]
+fn regex_any() {
+
+    assert!(Number::check("0xff").is_ok());
+    assert!(Number::check("0b01").is_ok());
+    assert!(Number::check("23").is_ok());
+
+    let error = Number::check("0xzz").err().expect("invalid value");
+    assert_eq!(error.patterns, &[r"^0x[0-9a-f]+$", r"^0b[01]+$", r"^[0-9]+$"]);
+    assert_eq!(
+        format!("{}", error),
+        "value does not match any of the patterns `^0x[0-9a-f]+$`, `^0b[01]+$`, `^[0-9]+$`",
+    );
+}
+
+#[test]
+fn regex_any_kind() {
+
+    struct NumberKind;
+
+    impl textkind::Kind for NumberKind {
+        type Check = And<MaxBytes64, Number>;
+        const DESCRIPTION: &'static str = "number";
+    }
+
+    let text = textkind::Text::<NumberKind, String>::try_from_str("0b1").unwrap();
+    assert_eq!(text.as_str(), "0b1");
+    assert!(textkind::Text::<NumberKind, String>::try_from_str("b1").is_err());
+}
+
+#[test]
+#[should_panic(expected = "valid regular expressions")]
+fn invalid_pattern() {
+
+    static SET: LazyRegexSet = LazyRegexSet::new(&["("]);
+    let _ = SET.check("foo");
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "rkyv")]
+
+extern crate textkind;
+extern crate rkyv;
+
+use rkyv::rancor;
+use rkyv::string::ArchivedString;
+
+#[test]
+fn roundtrip() {
+
+    let text = textkind::Title::<String>::try_from_str("foo").unwrap();
+    let bytes = rkyv::to_bytes::<rancor::Error>(&text).unwrap();
+
+    let archived = rkyv::access::<ArchivedString, rancor::Error>(&bytes).unwrap();
+    assert_eq!(archived.as_str(), "foo");
+
+    let restored: textkind::Title<String> =
+        rkyv::from_bytes::<_, rancor::Error>(&bytes).unwrap();
+    assert_eq!(restored.as_str(), "foo");
+
+    let long = textkind::Title::<String>::try_from_str(&"x".repeat(64)).unwrap();
+    let bytes = rkyv::to_bytes::<rancor::Error>(&long).unwrap();
+    let restored: textkind::Title<String> =
+        rkyv::from_bytes::<_, rancor::Error>(&bytes).unwrap();
+    assert_eq!(restored, long);
+}
+
+#[test]
+fn deserialize_checks() {
+
+    let text = textkind::Title::<String>::try_from_str("foo bar").unwrap();
+    let bytes = rkyv::to_bytes::<rancor::Error>(&text).unwrap();
+
+    let result = rkyv::from_bytes::<textkind::Identifier<String>, rancor::Error>(&bytes);
+    let error = result.err().expect("title with whitespace is not an identifier");
+    assert!(format!("{}", error).contains("invalid identifier"));
+
+    let archived = rkyv::access::<ArchivedString, rancor::Error>(&bytes).unwrap();
+    let result: Result<textkind::Identifier<String>, _> =
+        textkind::rkyv_support::try_from_archived(archived);
+    assert!(result.is_err());
+
+    let trusted: textkind::Title<String> =
+        textkind::rkyv_support::from_archived_trusted(archived);
+    assert_eq!(trusted.as_str(), "foo bar");
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid identifier")]
+fn trusted_asserts_in_debug() {
+
+    let text = textkind::Title::<String>::try_from_str("foo bar").unwrap();
+    let bytes = rkyv::to_bytes::<rancor::Error>(&text).unwrap();
+    let archived = rkyv::access::<ArchivedString, rancor::Error>(&bytes).unwrap();
+
+    let _: textkind::Identifier<String> = textkind::rkyv_support::from_archived_trusted(archived);
+}
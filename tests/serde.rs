@@ -27,3 +27,72 @@ fn deserialize_errors() {
     assert!(format!("{}", error).contains("invalid title"));
     assert!(format!("{}", error).contains("is empty"));
 }
+
+#[test]
+fn deserialize_wrong_type() {
+
+    let result: Result<textkind::Title<String>, _> = serde_json::from_str("23");
+    let error = result.err().expect("number should fail");
+    assert!(format!("{}", error).contains("expected a string for title"));
+}
+
+#[test]
+fn deserialize_owned() {
+
+    let value = serde_json::Value::String("foo".into());
+    let text: textkind::Title<String> = serde_json::from_value(value).unwrap();
+    assert_eq!(text.as_str(), "foo");
+
+    let value = serde_json::Value::String("".into());
+    let result: Result<textkind::Title<String>, _> = serde_json::from_value(value);
+    let error = result.err().expect("empty string should fail");
+    assert!(format!("{}", error).contains("invalid title"));
+}
+
+#[test]
+fn with_functions() {
+
+    let text = textkind::Title::<String>::try_from_str("foo").unwrap();
+    let value = textkind::serde_support::serialize(&text, serde_json::value::Serializer)
+        .unwrap();
+    assert_eq!(value, serde_json::Value::String("foo".into()));
+
+    let text: textkind::Title<String> = textkind::serde_support::deserialize(value).unwrap();
+    assert_eq!(text.as_str(), "foo");
+
+    let value = serde_json::Value::Bool(true);
+    let result: Result<textkind::Title<String>, _> =
+        textkind::serde_support::deserialize(value);
+    let error = result.err().expect("boolean should fail");
+    assert!(format!("{}", error).contains("title"));
+}
+
+#[test]
+fn from_number() {
+    use textkind::serde_support::FromNumber;
+
+    let id: FromNumber<textkind::IdentifierLax<String>> = serde_json::from_str("42").unwrap();
+    assert_eq!(id.0.as_str(), "42");
+
+    let id: FromNumber<textkind::Title<String>> = serde_json::from_str("-7").unwrap();
+    assert_eq!(id.0.as_str(), "-7");
+
+    let id: FromNumber<textkind::IdentifierLax<String>> = serde_json::from_str("\"foo\"").unwrap();
+    assert_eq!(id.into_inner().as_str(), "foo");
+
+    let result: Result<FromNumber<textkind::Identifier<String>>, _> =
+        serde_json::from_str("7");
+    let error = result.err().expect("invalid identifier");
+    assert!(format!("{}", error).contains("invalid identifier"));
+
+    let result: Result<FromNumber<textkind::IdentifierLax<String>>, _> =
+        serde_json::from_str("4.2");
+    let error = result.err().expect("float should fail");
+    assert!(format!("{}", error).contains("expected a string or integer for identifier"));
+
+    let id = FromNumber(textkind::IdentifierLax::<String>::try_from_str("42").unwrap());
+    assert_eq!(serde_json::to_string(&id).unwrap(), "\"42\"");
+
+    let result: Result<textkind::IdentifierLax<String>, _> = serde_json::from_str("42");
+    assert!(result.is_err());
+}
@@ -143,6 +143,40 @@ macro_rules! text_tests {
                 assert_eq!(error.value(), "");
             }
 
+            #[test]
+            fn try_from_chars() {
+
+                let text = Test::try_from_chars("foo".chars())
+                    .expect("valid value");
+                assert_eq!(text.as_str(), "foo");
+                assert!(text.into_data().is_small());
+
+                let long = "X".repeat(17);
+                let text = Test::try_from_chars(long.chars())
+                    .expect("valid value");
+                assert!(text.into_data().is_dynamic());
+
+                let error = Test::try_from_chars("".chars())
+                    .err()
+                    .expect("invalid value");
+                assert!(format!("{:?}", error).contains("NotEmptyError"));
+                assert_eq!(error.value(), "");
+            }
+
+            #[test]
+            fn try_from_boxed_str() {
+
+                let text = Test::try_from_boxed_str("foo".into())
+                    .expect("valid value");
+                assert_eq!(text.as_str(), "foo");
+
+                let error = Test::try_from_boxed_str("".into())
+                    .err()
+                    .expect("invalid value");
+                assert!(format!("{:?}", error).contains("NotEmptyError"));
+                assert_eq!(&**error.value(), "");
+            }
+
             #[test]
             fn try_from_data() {
                 use Dynamic;
@@ -321,6 +355,407 @@ macro_rules! text_tests {
                 assert_eq!(other.as_str(), "foo");
             }
 
+            #[test]
+            fn debug_assert_valid() {
+
+                let text = Test::try_from_str("foo").unwrap();
+                let text = text.debug_assert_valid();
+                assert_eq!(text.as_str(), "foo");
+            }
+
+            #[test]
+            fn try_trim() {
+
+                let text = Test::try_from_str("  foo ").unwrap();
+                let text = text.try_trim().expect("valid value");
+                assert_eq!(text.as_str(), "foo");
+
+                let text = Test::try_from_static_str("  foo ").unwrap();
+                let text = text.try_trim().expect("valid value");
+                assert_eq!(text.as_str(), "foo");
+                assert!(text.into_data().is_static());
+
+                let long = format!("  {}  ", "x".repeat(32));
+                let text = Test::try_from_str(&long).unwrap();
+                let text = text.try_trim().expect("valid value");
+                assert_eq!(text.as_str(), long.trim());
+                assert!(text.into_data().is_dynamic());
+
+                let text = Test::try_from_str("   ").unwrap();
+                let error = text.try_trim()
+                    .err()
+                    .expect("invalid value");
+                assert!(format!("{:?}", error).contains("NotEmptyError"));
+                assert_eq!(error.value().as_str(), "   ");
+            }
+
+            #[test]
+            fn display_truncated() {
+
+                let text = Test::try_from_str("foo bar").unwrap();
+                assert_eq!(text.display_truncated(3).to_string(), "foo\u{2026}");
+                assert_eq!(text.display_truncated(0).to_string(), "\u{2026}");
+                assert_eq!(text.display_truncated(7).to_string(), "foo bar");
+                assert_eq!(text.display_truncated(10).to_string(), "foo bar");
+
+                let text = Test::try_from_str("äöü").unwrap();
+                assert_eq!(text.display_truncated(2).to_string(), "äö\u{2026}");
+                assert_eq!(text.display_truncated(3).to_string(), "äöü");
+            }
+
+            #[test]
+            fn escape() {
+
+                let text = Test::try_from_str("a\tä\n").unwrap();
+                assert_eq!(text.escape_default(), "a\\t\\u{e4}\\n");
+                assert_eq!(text.escape_debug(), "a\\tä\\n");
+            }
+
+            #[test]
+            fn repeat() {
+
+                let text = Test::try_from_str("ab").unwrap();
+                assert_eq!(text.repeat(3).expect("valid value").as_str(), "ababab");
+
+                let error = text.repeat(0)
+                    .err()
+                    .expect("invalid value");
+                assert!(format!("{:?}", error).contains("NotEmptyError"));
+                assert_eq!(error.value(), "");
+            }
+
+            #[test]
+            fn try_truncate_bytes() {
+
+                let text = Test::try_from_str("foo").unwrap();
+                let text = text.try_truncate_bytes(3).expect("valid value");
+                assert_eq!(text.as_str(), "foo");
+                let text = text.try_truncate_bytes(2).expect("valid value");
+                assert_eq!(text.as_str(), "fo");
+
+                let text = Test::try_from_static_str("äöü").unwrap();
+                let text = text.try_truncate_bytes(5).expect("valid value");
+                assert_eq!(text.as_str(), "äö");
+                assert!(text.clone().into_data().is_static());
+                let error = text.try_truncate_bytes(1).err().expect("invalid value");
+                assert!(format!("{:?}", error).contains("NotEmptyError"));
+                assert_eq!(error.value().as_str(), "äö");
+
+                let long = "x".repeat(64);
+                let text = Test::try_from_str(&long).unwrap();
+                assert!(text.clone().try_truncate_bytes(64).unwrap().into_data().is_dynamic());
+                assert!(text.try_truncate_bytes(8).unwrap().into_data().is_small());
+            }
+
+            #[test]
+            fn write_to() {
+                use std::fmt::Write;
+
+                let text = Test::try_from_str("foo").unwrap();
+                let mut buffer = String::new();
+                text.write_to(&mut buffer).unwrap();
+                buffer.write_char(',').unwrap();
+                text.write_to(&mut buffer).unwrap();
+                assert_eq!(buffer, "foo,foo");
+            }
+
+            #[test]
+            fn byte_access() {
+
+                let text = Test::try_from_str("aä").unwrap();
+                assert_eq!(text.bytes().collect::<Vec<_>>(), [b'a', 0xc3, 0xa4]);
+                assert_eq!(text.byte_at(0), Some(b'a'));
+                assert_eq!(text.byte_at(2), Some(0xa4));
+                assert_eq!(text.byte_at(3), None);
+            }
+
+            #[test]
+            fn char_iterators() {
+
+                let text = Test::try_from_str("aäb").unwrap();
+                assert_eq!(text.chars().collect::<Vec<_>>(), ['a', 'ä', 'b']);
+                assert_eq!(
+                    text.char_indices().collect::<Vec<_>>(),
+                    [(0, 'a'), (1, 'ä'), (3, 'b')],
+                );
+            }
+
+            #[test]
+            fn char_access() {
+
+                let text = Test::try_from_str("äbc").unwrap();
+                assert_eq!(text.char_at(0), Some('ä'));
+                assert_eq!(text.char_at(1), Some('b'));
+                assert_eq!(text.char_at(2), Some('c'));
+                assert_eq!(text.char_at(3), None);
+                assert_eq!(text.first_char(), Some('ä'));
+                assert_eq!(text.last_char(), Some('c'));
+            }
+
+            #[test]
+            fn common_prefix() {
+                use std::rc::Rc;
+
+                let text = Test::try_from_str("foobar").unwrap();
+                let other = Text::<super::TestKind, Rc<String>>::try_from_str("foobaz").unwrap();
+                assert_eq!(text.common_prefix_len(&other), 5);
+                assert_eq!(text.common_prefix(&other), "fooba");
+
+                assert_eq!(text.common_prefix(&text), "foobar");
+
+                let shorter = Test::try_from_str("foo").unwrap();
+                assert_eq!(text.common_prefix(&shorter), "foo");
+                assert_eq!(shorter.common_prefix(&text), "foo");
+
+                let other = Test::try_from_str("bar").unwrap();
+                assert_eq!(text.common_prefix_len(&other), 0);
+
+                let text = Test::try_from_str("aäb").unwrap();
+                let other = Test::try_from_str("aöb").unwrap();
+                assert_eq!(text.common_prefix(&other), "a");
+                let other = Test::try_from_str("aä").unwrap();
+                assert_eq!(text.common_prefix_len(&other), 3);
+            }
+
+            #[test]
+            fn try_pad() {
+
+                let text = Test::try_from_str("foo").unwrap();
+                assert_eq!(text.clone().try_pad_start(5, '-').unwrap().as_str(), "--foo");
+                assert_eq!(text.clone().try_pad_end(5, '-').unwrap().as_str(), "foo--");
+                assert_eq!(text.clone().try_pad_start(3, '-').unwrap().as_str(), "foo");
+                assert_eq!(text.clone().try_pad_end(0, '-').unwrap().as_str(), "foo");
+                assert_eq!(text.clone().try_pad_start(6, 'ä').unwrap().as_str(), "ääfoo");
+                assert_eq!(text.clone().try_pad_end(4, 'ä').unwrap().as_str(), "fooä");
+
+                let long = text.try_pad_end(40, '-').unwrap();
+                assert_eq!(long.as_str().len(), 40);
+                assert!(long.into_data().is_dynamic());
+            }
+
+            #[test]
+            fn clone_into_string_buf() {
+
+                let mut buf = String::with_capacity(64);
+                let ptr = buf.as_ptr();
+                for value in &["foo", "ba", "bazqux"] {
+                    let text = Test::try_from_str(value).unwrap();
+                    text.clone_into_string_buf(&mut buf);
+                    assert_eq!(&buf, value);
+                }
+                assert_eq!(buf.as_ptr(), ptr);
+            }
+
+            #[test]
+            fn ascii_case() {
+
+                let text = Test::try_from_str("FooÄ").unwrap();
+                assert!(!text.is_ascii());
+                assert_eq!(text.try_to_ascii_lowercase().unwrap().as_str(), "fooÄ");
+                assert_eq!(text.try_to_ascii_uppercase().unwrap().as_str(), "FOOÄ");
+
+                let text = Test::try_from_static_str("foo").unwrap();
+                assert!(text.is_ascii());
+                assert!(text.try_to_ascii_lowercase().unwrap().into_data().is_static());
+                assert!(!text.try_to_ascii_uppercase().unwrap().into_data().is_static());
+            }
+
+            #[test]
+            fn to_string_storage() {
+
+                let text = Test::try_from_static_str("foo").unwrap();
+                assert!(text.to_string_storage().into_data().is_static());
+
+                let text = Test::try_from_str("foo").unwrap();
+                assert!(text.to_string_storage().into_data().is_small());
+
+                let long = "x".repeat(32);
+                let text = Test::try_from_str(&long).unwrap();
+                let owned = text.to_string_storage();
+                assert_eq!(owned.as_str(), long);
+                assert_ne!(owned.as_str().as_ptr(), text.as_str().as_ptr());
+                assert!(owned.into_data().is_dynamic());
+            }
+
+            #[test]
+            fn normalize_whitespace() {
+
+                let text = Test::try_from_str("  foo \t\n  bar  ").unwrap();
+                assert_eq!(text.normalize_whitespace().unwrap().as_str(), "foo bar");
+
+                let text = Test::try_from_static_str("foo bar").unwrap();
+                assert!(text.normalize_whitespace().unwrap().into_data().is_static());
+
+                let text = Test::try_from_str(" \t ").unwrap();
+                let error = text.normalize_whitespace().err().expect("empty value");
+                assert_eq!(error.1, "");
+            }
+
+            #[test]
+            fn try_push_char() {
+
+                let text = Test::try_from_str("abc").unwrap();
+                let text = text.try_push_char('ä').unwrap();
+                assert_eq!(text.as_str(), "abcä");
+                assert!(text.into_data().is_small());
+
+                let text = Test::try_from_str(&"x".repeat(15)).unwrap();
+                let text = text.try_push_char('y').unwrap();
+                assert_eq!(text.as_str(), format!("{}y", "x".repeat(15)));
+                assert!(text.into_data().is_small());
+
+                let text = Test::try_from_str(&"x".repeat(15)).unwrap();
+                let text = text.try_push_char('ä').unwrap();
+                assert_eq!(text.as_str(), format!("{}ä", "x".repeat(15)));
+                assert!(text.into_data().is_dynamic());
+            }
+
+            #[test]
+            fn to_titlecase() {
+
+                let text = Test::try_from_str("the lord of the rings").unwrap();
+                assert_eq!(text.to_titlecase().unwrap().as_str(), "The Lord Of The Rings");
+
+                let text = Test::try_from_str("mIxEd\tcASE  äbc").unwrap();
+                assert_eq!(text.to_titlecase().unwrap().as_str(), "Mixed\tCase  Äbc");
+
+                let text = Test::try_from_static_str("Already Done").unwrap();
+                assert!(text.to_titlecase().unwrap().into_data().is_static());
+            }
+
+            #[test]
+            fn try_from_str_sso() {
+
+                let text = Test::try_from_str_sso("foo", 3).unwrap();
+                assert!(text.into_data().is_small());
+
+                let text = Test::try_from_str_sso("foo", 2).unwrap();
+                assert_eq!(text.as_str(), "foo");
+                assert!(text.into_data().is_dynamic());
+
+                let long = "x".repeat(17);
+                let text = Test::try_from_str_sso(&long, 64).unwrap();
+                assert!(text.into_data().is_dynamic());
+
+                assert!(Test::try_from_str_sso("", 16).is_err());
+            }
+
+            #[test]
+            fn try_from_str_auto_boundary() {
+
+                let max = "x".repeat(textkind::SMALL_STRING_BUF);
+                let text = Test::try_from_str_auto(&max, textkind::SMALL_STRING_BUF).unwrap();
+                assert!(text.into_data().is_small());
+
+                let over = "x".repeat(textkind::SMALL_STRING_BUF + 1);
+                let text = Test::try_from_str_auto(&over, textkind::SMALL_STRING_BUF).unwrap();
+                assert_eq!(text.as_str(), over);
+                assert!(text.into_data().is_dynamic());
+
+                let text = Test::try_from_str_auto("foo", 2).unwrap();
+                assert!(text.into_data().is_dynamic());
+
+                assert!(Test::try_from_str_auto("", textkind::SMALL_STRING_BUF).is_err());
+            }
+
+            #[test]
+            fn try_from_str_sso_boundary() {
+
+                let max = "x".repeat(textkind::SMALL_STRING_BUF);
+                let text = Test::try_from_str_sso(&max, textkind::SMALL_STRING_BUF).unwrap();
+                assert!(text.into_data().is_small());
+
+                let text = Test::try_from_str_sso(&max, textkind::SMALL_STRING_BUF - 1).unwrap();
+                assert!(text.into_data().is_dynamic());
+
+                let over = "x".repeat(textkind::SMALL_STRING_BUF + 1);
+                let text = Test::try_from_str_sso(&over, usize::MAX).unwrap();
+                assert_eq!(text.as_str(), over);
+                assert!(text.into_data().is_dynamic());
+            }
+
+            #[test]
+            fn eq_reversed() {
+
+                let text = Test::try_from_str("foo").unwrap();
+                let string = "foo".to_string();
+                assert!("foo" == text);
+                assert!(*"foo" == text);
+                assert!(string == text);
+                assert!("bar" != text);
+                assert_eq!("foo", text);
+                assert_eq!(string, text);
+            }
+
+            #[test]
+            fn try_from_char() {
+
+                let text = Test::try_from_char('x').unwrap();
+                assert_eq!(text.as_str(), "x");
+                assert_eq!(text.storage_kind(), StorageKind::Small);
+
+                let text = Test::try_from_char('\u{1F980}').unwrap();
+                assert_eq!(text.as_str(), "\u{1F980}");
+                assert_eq!(text.storage_kind(), StorageKind::Small);
+            }
+
+            #[test]
+            fn revalidate() {
+
+                let text = Test::try_from_str("foo").unwrap();
+                assert!(text.revalidate().is_ok());
+
+                let text = Test::try_from_static_str("foo").unwrap();
+                assert!(text.revalidate().is_ok());
+            }
+
+            #[test]
+            fn display_format_flags() {
+
+                let text = Test::try_from_str("Käsebrot").unwrap();
+                assert_eq!(format!("{:.2}", text), "Kä");
+                assert_eq!(format!("{:.5}", text), "Käseb");
+                assert_eq!(format!("{:.20}", text), "Käsebrot");
+                assert_eq!(format!("{:>10}", text), "  Käsebrot");
+                assert_eq!(format!("{:-<6.3}", text), "Käs---");
+            }
+
+            #[test]
+            fn as_cow_all_storages() {
+                use std::borrow::Cow;
+
+                let texts = vec![
+                    Test::try_from_static_str("foo").unwrap(),
+                    Test::try_from_str("foo").unwrap(),
+                    Test::try_from_str(&"x".repeat(32)).unwrap(),
+                ];
+                for text in &texts {
+                    match text.as_cow() {
+                        Cow::Borrowed(value) => assert_eq!(value.as_ptr(), text.as_ptr()),
+                        Cow::Owned(_) => panic!("owned instead of borrowed"),
+                    }
+                }
+
+                assert!(match texts[0].clone().into_static_str_cow() {
+                    Cow::Borrowed(_) => true,
+                    Cow::Owned(_) => false,
+                });
+            }
+
+            #[test]
+            fn into_cow() {
+                use std::borrow::Cow;
+
+                match Test::try_from_static_str("foo").unwrap().into_cow() {
+                    Cow::Borrowed(value) => assert_eq!(value, "foo"),
+                    Cow::Owned(_) => panic!("owned instead of borrowed"),
+                }
+                match Test::try_from_str("foo").unwrap().into_cow() {
+                    Cow::Owned(value) => assert_eq!(value, "foo"),
+                    Cow::Borrowed(_) => panic!("borrowed instead of owned"),
+                }
+            }
+
             #[test]
             fn into_string() {
 
@@ -329,6 +764,17 @@ macro_rules! text_tests {
                 assert_eq!(&string, "foo");
             }
 
+            #[test]
+            fn as_cow() {
+
+                let text = Test::try_from_str(&"x".repeat(32)).unwrap();
+                match text.as_cow() {
+                    ::std::borrow::Cow::Borrowed(value) =>
+                        assert_eq!(value.as_ptr(), text.as_str().as_ptr()),
+                    ::std::borrow::Cow::Owned(_) => panic!("owned instead of borrowed"),
+                }
+            }
+
             #[test]
             fn into_static_str_cow() {
 
@@ -411,6 +857,7 @@ macro_rules! text_tests {
                 storage_transition_string: String,
                 storage_transition_arc_string: ::std::sync::Arc<String>,
                 storage_transition_rc_string: ::std::rc::Rc<String>,
+                storage_transition_boxed_str: Box<str>,
             }
         }
     }
@@ -419,6 +866,7 @@ macro_rules! text_tests {
 text_tests!(string: String);
 text_tests!(rc_string: ::std::rc::Rc<String>);
 text_tests!(arc_string: ::std::sync::Arc<String>);
+text_tests!(boxed_str: Box<str>);
 
 #[test]
 fn title() {
@@ -498,6 +946,51 @@ fn from_str() {
     assert!(result.is_err());
 }
 
+#[test]
+fn try_from_chars() {
+
+    let chars = "foo-bar".chars().filter(|c| c.is_alphanumeric());
+    let text = Identifier::<String>::try_from_chars(chars).expect("valid value");
+    assert_eq!(text.as_str(), "foobar");
+
+    let error = Identifier::<String>::try_from_chars("23".chars())
+        .err()
+        .expect("invalid value");
+    assert_eq!(error.value(), "23");
+}
+
+#[test]
+fn try_from_str_filtered() {
+
+    let text = Identifier::<String>::try_from_str_filtered("fo o!", |c| c.is_ascii_alphanumeric())
+        .expect("valid value");
+    assert_eq!(text.as_str(), "foo");
+
+    let mut seen = 0;
+    let text = Title::<String>::try_from_str_filtered("a-b-c", |c| {
+        seen += 1;
+        c != '-'
+    }).expect("valid value");
+    assert_eq!(text.as_str(), "abc");
+    assert_eq!(seen, 5);
+
+    let error = Identifier::<String>::try_from_str_filtered("!1-2", |c| c != '!')
+        .err()
+        .expect("invalid value");
+    assert_eq!(error.value(), "1-2");
+}
+
+#[test]
+fn parse_with() {
+    use std::sync::Arc;
+
+    let text = Title::parse_with::<Arc<String>>("foo").expect("valid value");
+    let _: &Title<Arc<String>> = &text;
+    assert_eq!(text.as_str(), "foo");
+
+    assert!(Title::parse_with::<String>("foo\nbar").is_err());
+}
+
 #[test]
 fn debug() {
 
@@ -552,6 +1045,21 @@ fn as_ref() {
     assert_eq!(slice, "foo");
 }
 
+#[test]
+fn as_ref_bytes() {
+
+    fn byte_len<T: AsRef<[u8]>>(value: T) -> usize { value.as_ref().len() }
+
+    let text = Title::<String>::try_from_str("käse").unwrap();
+    let bytes: &[u8] = text.as_ref();
+    assert_eq!(bytes, "käse".as_bytes());
+    assert_eq!(byte_len(&text), 5);
+
+    let mut buffer = Vec::new();
+    ::std::io::Write::write_all(&mut buffer, text.as_ref()).unwrap();
+    assert_eq!(buffer, b"k\xc3\xa4se");
+}
+
 #[test]
 fn deref() {
 
@@ -560,3 +1068,547 @@ fn deref() {
     assert_eq!(slice, "foo");
 }
 
+
+#[test]
+fn repeat_bounded() {
+
+    let text = Title::<String>::try_from_str("abcd").unwrap();
+    assert_eq!(text.repeat(128).expect("valid value").as_str().len(), 512);
+
+    let error = text.repeat(129)
+        .err()
+        .expect("invalid value");
+    assert!(format!("{:?}", error).contains("MaxBytesError"));
+    assert_eq!(error.value().len(), 516);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn repeat_overflow() {
+
+    let text = Title::<String>::try_from_str("abcd").unwrap();
+    let _ = text.repeat(usize::MAX);
+}
+
+#[test]
+fn try_truncate_bytes_revalidates() {
+
+    let text = Title::<String>::try_from_str("foo bar").unwrap();
+    let error = text.try_truncate_bytes(4)
+        .err()
+        .expect("invalid value");
+    assert!(format!("{:?}", error).contains("TrimmedRightError"));
+    assert_eq!(error.value().as_str(), "foo bar");
+}
+
+#[test]
+fn cmp_str() {
+    use std::cmp::Ordering;
+    use std::rc::Rc;
+
+    let title = Title::<String>::try_from_static_str("b").unwrap();
+    let identifier = Identifier::<Rc<String>>::try_from_str("b").unwrap();
+    assert_eq!(title.cmp_str(&identifier), Ordering::Equal);
+
+    let other = Identifier::<Rc<String>>::try_from_str("c").unwrap();
+    assert_eq!(title.cmp_str(&other), Ordering::Less);
+    assert_eq!(other.cmp_str(&title), Ordering::Greater);
+
+    let mut texts: Vec<Title<Box<str>>> = ["c", "a", "b"].iter()
+        .map(|value| Title::try_from_str(value).unwrap())
+        .collect();
+    texts.sort_by(|a, b| a.cmp_str(b));
+    let sorted: Vec<&str> = texts.iter().map(|text| text.as_str()).collect();
+    assert_eq!(sorted, ["a", "b", "c"]);
+}
+
+#[test]
+fn check_as() {
+
+    let text = Title::<String>::try_from_str("foo").unwrap();
+    assert!(text.is_valid_as::<kind::Identifier>());
+    assert!(text.check_as::<kind::Identifier>().is_ok());
+
+    let text = Title::<String>::try_from_str("foo bar").unwrap();
+    assert!(!text.is_valid_as::<kind::Identifier>());
+    let error = text.check_as::<kind::Identifier>()
+        .err()
+        .expect("invalid value");
+    assert_eq!(&format!("{}", error), "invalid identifier");
+    assert!(format!("{:?}", error).contains("InvalidRestChar"));
+}
+
+#[test]
+fn try_pad_revalidates() {
+
+    let text = Title::<String>::try_from_str("foo").unwrap();
+    let error = text.try_pad_end(5, ' ')
+        .err()
+        .expect("invalid value");
+    assert!(format!("{:?}", error).contains("TrimmedRightError"));
+    assert_eq!(error.value().as_str(), "foo");
+}
+
+#[test]
+fn ascii_case_revalidates() {
+
+    let text = Identifier::<String>::try_from_str("foo").unwrap();
+    assert_eq!(text.try_to_ascii_uppercase().unwrap().as_str(), "FOO");
+
+    let text = Tag::<String>::try_from_str("foo").unwrap();
+    let error = text.try_to_ascii_uppercase()
+        .err()
+        .expect("invalid value");
+    assert!(format!("{:?}", error).contains("InvalidChar"));
+    assert_eq!(error.value(), "FOO");
+}
+
+#[test]
+fn try_from_str_with_context() {
+    use std::collections::HashSet;
+
+    struct NotReserved;
+
+    impl ContextCheck for NotReserved {
+
+        type Context = HashSet<&'static str>;
+        type Error = String;
+
+        fn check_with(value: &str, reserved: &Self::Context) -> Result<(), Self::Error> {
+            if reserved.contains(value) {
+                Err(format!("{} is reserved", value))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    let reserved: HashSet<_> = vec!["admin", "root"].into_iter().collect();
+
+    let text = Identifier::<String>::try_from_str_with_context::<NotReserved>("foo", &reserved)
+        .expect("valid value");
+    assert_eq!(text.as_str(), "foo");
+
+    let error = Identifier::<String>::try_from_str_with_context::<NotReserved>("root", &reserved)
+        .err()
+        .expect("invalid value");
+    assert_eq!(error, check::AndError::Err2("root is reserved".to_string()));
+
+    let error = Identifier::<String>::try_from_str_with_context::<NotReserved>("", &reserved)
+        .err()
+        .expect("invalid value");
+    assert!(format!("{:?}", error).contains("NotEmptyError"));
+}
+
+#[test]
+fn imply_kind() {
+
+    struct Slug;
+    struct Label;
+
+    impl Kind for Slug {
+        type Check = check::Tag;
+        const DESCRIPTION: &'static str = "slug";
+    }
+
+    impl Kind for Label {
+        type Check = check::NotEmpty;
+        const DESCRIPTION: &'static str = "label";
+    }
+
+    impl Implies<Label> for Slug {}
+
+    let slug = Text::<Slug, String>::try_from_static_str("rust-lang").unwrap();
+    let label: Text<Label, String> = slug.imply_kind();
+    assert_eq!(label.as_str(), "rust-lang");
+    assert!(label.into_data().is_static());
+}
+
+#[test]
+fn join() {
+
+    let ids = vec![
+        Identifier::<String>::try_from_str("foo").unwrap(),
+        Identifier::<String>::try_from_str("bar").unwrap(),
+    ];
+
+    let path: Title<String> = textkind::join(&ids, ".").unwrap();
+    assert_eq!(path.as_str(), "foo.bar");
+    assert!(path.into_data().is_small());
+
+    let error = textkind::join::<kind::Identifier, String, _>(&ids, ".")
+        .err()
+        .expect("invalid value");
+    assert_eq!(error.1, "foo.bar");
+
+    let single: Title<String> = textkind::join(vec!["foo"], ".").unwrap();
+    assert_eq!(single.as_str(), "foo");
+
+    let empty = textkind::join::<kind::Title, String, _>(Vec::<&str>::new(), ".");
+    assert_eq!(empty.err().expect("empty value").1, "");
+}
+
+#[test]
+fn normalize_whitespace_revalidates() {
+
+    struct NoSpaceKind;
+
+    impl Kind for NoSpaceKind {
+        type Check = check::And<check::NotEmpty, NoSpace>;
+        const DESCRIPTION: &'static str = "no space";
+    }
+
+    enum NoSpace {}
+
+    impl Check for NoSpace {
+
+        type Error = usize;
+
+        fn check(value: &str) -> Result<(), Self::Error> {
+            match value.find(' ') {
+                Some(index) => Err(index),
+                None => Ok(()),
+            }
+        }
+    }
+
+    let text = Text::<NoSpaceKind, String>::try_from_str("foo\tbar").unwrap();
+    let error = text.normalize_whitespace().err().expect("invalid value");
+    assert_eq!(error.0, check::AndError::Err2(3));
+    assert_eq!(error.1, "foo bar");
+
+    let text = Text::<NoSpaceKind, String>::try_from_str("\tfoo\n").unwrap();
+    assert_eq!(text.normalize_whitespace().unwrap().as_str(), "foo");
+}
+
+#[test]
+fn eq_across_kinds() {
+    use std::rc::Rc;
+
+    let title = Title::<String>::try_from_str("foo").unwrap();
+    let ident = Identifier::<Rc<String>>::try_from_static_str("foo").unwrap();
+    let other = Identifier::<String>::try_from_str("bar").unwrap();
+
+    assert!(title == ident);
+    assert!(ident == title);
+    assert!(title != other);
+    assert_eq!(title, ident);
+}
+
+#[test]
+fn try_from_char_invalid() {
+
+    assert!(Identifier::<String>::try_from_char('a').is_ok());
+
+    let error = Identifier::<String>::try_from_char('2').err().expect("invalid value");
+    assert_eq!(error.0, check::AndError::Err2(check::IdentifierError::InvalidStartChar('2')));
+}
+
+#[test]
+fn checked_slice() {
+
+    let text = Title::<String>::try_from_static_str("key: some_value").unwrap();
+
+    let ident: Identifier<String> = text.checked_slice(5..15).unwrap();
+    assert_eq!(ident.as_str(), "some_value");
+    assert!(ident.into_data().is_static());
+
+    let title: Title<String> = text.checked_slice(0..3).unwrap();
+    assert_eq!(title.as_str(), "key");
+
+    let error = text.checked_slice::<kind::Identifier>(0..4).err().expect("invalid value");
+    assert!(format!("{:?}", error).starts_with("Invalid("));
+    assert_eq!(error.to_string(), "invalid identifier");
+
+    let error = text.checked_slice::<kind::Identifier>(5..16).err().expect("out of bounds");
+    assert_eq!(error, SliceError::InvalidRange { start: 5, end: 16 });
+    assert_eq!(error.to_string(), "invalid range 5..16");
+
+    let text = Title::<String>::try_from_str("käse").unwrap();
+    let error = text.checked_slice::<kind::Title>(0..2).err().expect("not a char boundary");
+    assert_eq!(error, SliceError::InvalidRange { start: 0, end: 2 });
+
+    let rest: Title<String> = text.checked_slice(1..5).unwrap();
+    assert_eq!(rest.as_str(), "äse");
+    assert!(rest.into_data().is_small());
+}
+
+#[test]
+fn storage_transition_moves_string() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let value = "x".repeat(32).into_boxed_str().into_string();
+    let pointer = value.as_ptr();
+    let text = Title::<String>::try_from_string(value).unwrap();
+
+    let text: Title<Box<str>> = text.storage_transition();
+    assert_eq!(text.as_ptr(), pointer);
+
+    let text: Title<Rc<String>> = text.storage_transition();
+    assert_eq!(text.as_ptr(), pointer);
+
+    let text: Title<Arc<String>> = text.storage_transition();
+    assert_eq!(text.as_ptr(), pointer);
+
+    let text: Title<String> = text.storage_transition();
+    assert_eq!(text.as_ptr(), pointer);
+}
+
+#[test]
+fn try_from_utf8() {
+
+    let text = Title::<String>::try_from_utf8("käse".as_bytes()).unwrap();
+    assert_eq!(text.as_str(), "käse");
+
+    let error = Title::<String>::try_from_utf8(b"foo\xffbar").err().expect("invalid UTF-8");
+    match error {
+        TextFromBytesError::Utf8(ref error) => assert_eq!(error.valid_up_to(), 3),
+        TextFromBytesError::Check(_) => panic!("expected UTF-8 error"),
+    }
+    assert!(error.to_string().starts_with("invalid title: "));
+
+    let error = Title::<String>::try_from_utf8(b"foo\nbar").err().expect("invalid title");
+    match error {
+        TextFromBytesError::Check(ref error) => assert_eq!(error.to_string(), "invalid title"),
+        TextFromBytesError::Utf8(_) => panic!("expected check error"),
+    }
+}
+
+#[test]
+fn try_from_utf8_vec() {
+
+    let bytes = "x".repeat(32).into_bytes();
+    let pointer = bytes.as_ptr();
+    let text = Title::<String>::try_from_utf8_vec(bytes).unwrap();
+    assert_eq!(text.as_ptr(), pointer);
+
+    let error = Title::<String>::try_from_utf8_vec(vec![0xc3]).err().expect("invalid UTF-8");
+    assert!(format!("{:?}", error).starts_with("Utf8("));
+
+    let error = Title::<String>::try_from_utf8_vec(Vec::new()).err().expect("empty title");
+    assert!(format!("{:?}", error).starts_with("Check("));
+}
+
+#[test]
+fn try_split_once_as() {
+
+    let text = Title::<String>::try_from_static_str("key=Some Value").unwrap();
+    let (key, value) = text
+        .try_split_once_as::<kind::Identifier, kind::Title>('=')
+        .expect("delimiter present")
+        .expect("valid parts");
+    assert_eq!(key.as_str(), "key");
+    assert_eq!(value.as_str(), "Some Value");
+    assert!(key.into_data().is_static());
+    assert!(value.into_data().is_static());
+
+    assert!(text.try_split_once_as::<kind::Identifier, kind::Title>(':').is_none());
+
+    let text = Title::<String>::try_from_str("a→b→c").unwrap();
+    let (left, right) = text
+        .try_split_once_as::<kind::Title, kind::Title>('→')
+        .expect("delimiter present")
+        .expect("valid parts");
+    assert_eq!(left.as_str(), "a");
+    assert_eq!(right.as_str(), "b→c");
+
+    let text = Title::<String>::try_from_str("2key=value").unwrap();
+    let error = text
+        .try_split_once_as::<kind::Identifier, kind::Title>('=')
+        .expect("delimiter present")
+        .err()
+        .expect("invalid key");
+    assert!(format!("{:?}", error).starts_with("Left("));
+    assert_eq!(error.to_string(), "left part: invalid identifier");
+
+    let text = Title::<String>::try_from_str("key=").unwrap();
+    let error = text
+        .try_split_once_as::<kind::Identifier, kind::Title>('=')
+        .expect("delimiter present")
+        .err()
+        .expect("empty value");
+    assert!(format!("{:?}", error).starts_with("Right("));
+    assert_eq!(error.to_string(), "right part: invalid title");
+}
+
+#[test]
+fn ascii_text_alias() {
+
+    let text = AsciiText::<String>::try_from_str("foo").unwrap();
+    assert!(text.is_ascii());
+
+    let error = AsciiText::<String>::try_from_str("föo").err().expect("invalid value");
+    assert_eq!(
+        error.explain(),
+        "invalid ASCII text: value contains character `\\u{f6}` at index 1 that is not ASCII",
+    );
+}
+
+#[test]
+fn sort_texts() {
+
+    let values = ["foo", "Bar", "bar", "BAR", "foo", "baz"];
+    let mut texts: Vec<Title<String>> = values.iter()
+        .map(|value| Title::try_from_str(value).unwrap())
+        .collect();
+
+    textkind::sort_texts(&mut texts);
+    assert_eq!(texts, ["BAR", "Bar", "bar", "baz", "foo", "foo"]);
+
+    let mut texts: Vec<Title<String>> = values.iter()
+        .map(|value| Title::try_from_str(value).unwrap())
+        .collect();
+
+    textkind::sort_texts_ci(&mut texts);
+    assert_eq!(texts, ["Bar", "bar", "BAR", "baz", "foo", "foo"]);
+}
+
+#[test]
+fn send_sync_errors() {
+    use std::error::Error as StdError;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    // The kind type itself doesn't have to be thread-safe.
+    #[allow(dead_code)]
+    struct LocalKind(Rc<()>);
+
+    impl Kind for LocalKind {
+        type Check = check::NotEmpty;
+        const DESCRIPTION: &'static str = "local";
+    }
+
+    assert_send_sync::<Text<LocalKind, String>>();
+    assert_send_sync::<Error<LocalKind>>();
+    assert_send_sync::<ErrorWithValue<LocalKind, String>>();
+    assert_send_sync::<ConvertError<LocalKind, Arc<String>, check::NotEmptyError>>();
+
+    let error: Box<dyn StdError + Send + Sync> = Title::<String>::try_from_str("")
+        .err()
+        .expect("empty title")
+        .boxed();
+    assert_eq!(error.to_string(), "invalid title");
+
+    let error = Text::<LocalKind, String>::try_from_string(String::new())
+        .err()
+        .expect("empty value")
+        .boxed();
+    assert_eq!(error.to_string(), "invalid local");
+
+    let text = Title::<Arc<String>>::try_from_str("foo").unwrap();
+    let error = ConvertError(check::NotEmptyError, text).boxed();
+    assert_eq!(error.to_string(), "value is empty");
+}
+
+#[test]
+fn to_titlecase_title() {
+
+    let text = Title::<String>::try_from_str("the lord of the rings").unwrap();
+    assert_eq!(text.to_titlecase().unwrap(), "The Lord Of The Rings");
+}
+
+#[test]
+fn shrink_string_storage() {
+
+    let mut value = String::with_capacity(128);
+    value.push_str("a title that does not fit inline");
+    let text = Title::<String>::try_from_string(value).unwrap();
+    let value = text.shrink().into_data().into_dynamic();
+    assert_eq!(value.capacity(), value.len());
+
+    let text = Title::<String>::try_from_static_str("static").unwrap();
+    assert!(text.shrink().into_data().is_static());
+}
+
+#[test]
+fn try_push_char_keeps_original() {
+
+    let text = Identifier::<String>::try_from_static_str("foo").unwrap();
+    let error = text.try_push_char('-').err().expect("invalid identifier");
+    assert!(error.value().clone().into_data().is_static());
+    assert_eq!(error.1, "foo");
+
+    let long = "x".repeat(20);
+    let text = Identifier::<String>::try_from_str(&long).unwrap();
+    let error = text.try_push_char(' ').err().expect("invalid identifier");
+    assert_eq!(error.1, long.as_str());
+}
+
+#[test]
+fn display_matches_str_formatting() {
+
+    let value = "The Title";
+    let title = Title::<String>::try_from_str(value).unwrap();
+    assert_eq!(format!("{:>10}", title), format!("{:>10}", value));
+    assert_eq!(format!("{:.3}", title), format!("{:.3}", value));
+    assert_eq!(format!("{:^15}", title), format!("{:^15}", value));
+    assert_eq!(format!("{:*<12.4}", title), format!("{:*<12.4}", value));
+}
+
+#[test]
+fn as_dynamic() {
+    use std::sync::Arc;
+
+    let shared = Arc::new("foo".to_string());
+    let text = Title::try_from_data(Data::from_dynamic(shared.clone())).unwrap();
+    let stored = text.as_dynamic().expect("dynamic storage");
+    assert!(Arc::ptr_eq(stored, &shared));
+    assert_eq!(Arc::strong_count(&shared), 2);
+
+    let text = Title::<Arc<String>>::try_from_static_str("foo").unwrap();
+    assert!(text.as_dynamic().is_none());
+
+    let text = Title::<Arc<String>>::try_from_str("foo").unwrap();
+    assert!(text.as_dynamic().is_none());
+}
+
+#[test]
+fn try_from_args() {
+    use std::rc::Rc;
+
+    let number = 23;
+    let text = textkind::text_format!(Identifier<Rc<String>>; "{}_{}", "foo", number).unwrap();
+    assert_eq!(text.as_str(), "foo_23");
+    assert!(text.into_data().is_small());
+
+    let long = "x".repeat(32);
+    let text = textkind::text_format!(Title<String>; "{} {}", long, long).unwrap();
+    assert_eq!(text.as_str().len(), 65);
+    assert!(text.into_data().is_dynamic());
+
+    let text = Title::<String>::try_from_args(format_args!("static")).unwrap();
+    assert!(text.into_data().is_static());
+
+    let error = textkind::text_format!(Title<String>; "{}\n", "foo").err().expect("invalid title");
+    assert_eq!(error.1, "foo\n");
+
+    let error = Title::<String>::try_from_args(format_args!("")).err().expect("empty title");
+    assert_eq!(error.1, "");
+}
+
+#[test]
+fn from_data_unchecked_round_trip() {
+
+    let text = Title::<String>::try_from_static_str("foo").unwrap();
+    let text = Title::<String>::from_data_unchecked(text.into_data());
+    assert_eq!(text.as_str(), "foo");
+    assert!(text.into_data().is_static());
+
+    let long = "x".repeat(32);
+    let text = Title::<String>::try_from_string(long.clone()).unwrap();
+    let pointer = text.as_ptr();
+    let text = Title::<String>::from_data_unchecked(text.into_data());
+    assert_eq!(text.as_ptr(), pointer);
+    assert_eq!(text, long.as_str());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid title")]
+fn from_data_unchecked_invalid() {
+
+    Title::<String>::from_data_unchecked(Data::from_static_str(""));
+}